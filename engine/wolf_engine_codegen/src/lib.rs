@@ -4,9 +4,20 @@ use syn::{parse_macro_input, DeriveInput};
 
 #[proc_macro_derive(Event)]
 pub fn derive_event(input: TokenStream) -> TokenStream {
-    let DeriveInput { ident, .. } = parse_macro_input!(input);
+    let DeriveInput {
+        ident, generics, ..
+    } = parse_macro_input!(input);
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let mut where_clause = where_clause
+        .cloned()
+        .unwrap_or_else(|| syn::parse_quote!(where));
+    // Event requires Debug + 'static, so generic events are only Events when their parameters
+    // allow for it.
+    where_clause
+        .predicates
+        .push(syn::parse_quote!(#ident #type_generics: ::core::fmt::Debug + 'static));
     let output = quote! {
-        impl Event for #ident {}
+        impl #impl_generics Event for #ident #type_generics #where_clause {}
     };
     output.into()
 }
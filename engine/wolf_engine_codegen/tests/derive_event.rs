@@ -0,0 +1,7 @@
+#[test]
+fn derive_event() {
+    let tests = trybuild::TestCases::new();
+    tests.pass("tests/ui/generic_event.rs");
+    tests.pass("tests/ui/lifetime_event.rs");
+    tests.compile_fail("tests/ui/non_static_event.rs");
+}
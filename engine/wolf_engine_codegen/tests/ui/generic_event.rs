@@ -0,0 +1,12 @@
+use wolf_engine_core::events::*;
+
+#[derive(Event, Debug)]
+struct GenericEvent<T>(T)
+where
+    T: Clone;
+
+fn main() {
+    let event: EventBox = Box::from(GenericEvent(42));
+
+    assert_eq!(event.downcast_ref::<GenericEvent<i32>>().unwrap().0, 42);
+}
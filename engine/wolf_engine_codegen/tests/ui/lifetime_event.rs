@@ -0,0 +1,13 @@
+use wolf_engine_core::events::*;
+
+#[derive(Event, Debug)]
+struct BorrowedEvent<'a>(&'a str);
+
+fn main() {
+    let event: EventBox = Box::from(BorrowedEvent("Hello, World!"));
+
+    assert_eq!(
+        event.downcast_ref::<BorrowedEvent<'static>>().unwrap().0,
+        "Hello, World!"
+    );
+}
@@ -0,0 +1,13 @@
+use wolf_engine_core::events::*;
+
+#[derive(Event, Debug)]
+struct BorrowedEvent<'a>(&'a str);
+
+fn into_event_box(message: &str) -> EventBox {
+    Box::from(BorrowedEvent(message))
+}
+
+fn main() {
+    let message = String::from("Hello, World!");
+    let _event = into_event_box(&message);
+}
@@ -1,15 +1,29 @@
+use std::any::TypeId;
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+
 use crate::events::mpsc::*;
 use crate::events::*;
-use crate::resources::Resources;
+use crate::plugins::Plugin;
+use crate::resources::{Resource, ResourceAlreadyExistsError, Resources};
+use crate::QuitState;
 
 /// Provides a container for Wolf Engine's user-facing data.
 ///
 /// Wolf Engine consists of two main parts: The `Context` (You are here!), and the
 /// [`EventLoop`](crate::EventLoop`).  The context owns the main [`EventSender`], as well as all
 /// [`Resources`] for the engine.
+///
+/// The context also keeps the loaded [`Plugins`](Plugin) alive, and runs their
+/// [teardown](Plugin::teardown()) when it is dropped.
+///
+/// The context is not [`Send`].  Neither [events](Event), nor [`Plugins`](Plugin) are required to
+/// be thread-safe, so the context has to stay on the thread the engine was built on.
 pub struct Context {
     pub(crate) resources: Resources,
     pub(crate) event_sender: MpscEventSender<EventBox>,
+    pub(crate) plugins: Vec<Box<dyn Plugin>>,
+    pub(crate) quit_state: Arc<QuitState>,
 }
 
 impl Context {
@@ -27,16 +41,205 @@ impl Context {
         &self.event_sender
     }
 
+    /// Mutably borrows two different resources at the same time.
+    ///
+    /// Returns `None` if either resource is missing, or already borrowed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `A` and `B` are the same type, since that would alias the same resource.
+    pub fn borrow_mut_two<A: Resource, B: Resource>(
+        &self,
+    ) -> Option<(
+        impl DerefMut<Target = A> + '_,
+        impl DerefMut<Target = B> + '_,
+    )> {
+        assert_ne!(
+            TypeId::of::<A>(),
+            TypeId::of::<B>(),
+            "Cannot mutably borrow the same resource twice"
+        );
+        let a = self.resources.get_mut::<A>().ok()?;
+        let b = self.resources.get_mut::<B>().ok()?;
+        Some((a, b))
+    }
+
+    /// Runs the closure with a shared reference to a resource, and returns its result.
+    ///
+    /// The borrow only lasts as long as the closure, so it can't linger around and conflict with
+    /// other borrows.  Returns `None` if the resource is missing, or already mutably borrowed.
+    pub fn with_resource<T: Resource, R>(&self, f: impl FnOnce(&T) -> R) -> Option<R> {
+        let resource = self.resources.get::<T>().ok()?;
+        Some(f(&resource))
+    }
+
+    /// Runs the closure with a mutable reference to a resource, and returns its result.
+    ///
+    /// The borrow only lasts as long as the closure, so it can't linger around and conflict with
+    /// other borrows.  Returns `None` if the resource is missing, or already borrowed.
+    pub fn with_resource_mut<T: Resource, R>(&self, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        let mut resource = self.resources.get_mut::<T>().ok()?;
+        Some(f(&mut resource))
+    }
+
+    /// Inserts a resource, replacing any existing resource of the same type.
+    ///
+    /// Returns the replaced resource, or `None` if there wasn't one.
+    pub fn add_or_replace<T: Resource>(&mut self, value: T) -> Option<T> {
+        let previous = self.resources.remove::<T>();
+        self.resources.insert(value);
+        previous
+    }
+
+    /// Inserts the [default](Default) value of a resource.
+    ///
+    /// Returns an error, and leaves the existing resource untouched, if a resource of the same type
+    /// already exists.
+    pub fn add_default<T: Resource + Default>(&mut self) -> Result<(), ResourceAlreadyExistsError> {
+        if self.resources.get::<T>().is_ok() {
+            return Err(ResourceAlreadyExistsError);
+        }
+        self.resources.insert(T::default());
+        Ok(())
+    }
+
+    /// Inserts a resource which is removed again when the returned [`ScopedContext`] is dropped.
+    ///
+    /// If a resource of the same type already exists, it is set aside for the duration of the
+    /// scope, and put back afterwards.  The scope dereferences to the `Context`, so it can be used
+    /// in its place while the scope is alive.  The scoped resource itself can be borrowed through
+    /// [`ScopedContext::resource()`], and [`ScopedContext::resource_mut()`].
+    pub fn with_scoped_resource<T: Resource>(&mut self, value: T) -> ScopedContext<'_, T> {
+        let previous = self.add_or_replace(value);
+        ScopedContext {
+            context: self,
+            previous,
+        }
+    }
+
     /// Sends a [Quit Event](EngineEvent::Quit) to trigger an engine shutdown.
+    ///
+    /// This is the same as calling [`Context::quit_with()`] with an exit code of `0`.
     pub fn quit(&self) {
+        self.quit_with(0);
+    }
+
+    /// Records the provided exit code, and sends a [Quit Event](EngineEvent::Quit) to trigger an
+    /// engine shutdown.
+    ///
+    /// The exit code can be read back with [`Context::exit_code()`].  The event carries a
+    /// [`QuitReason::UserRequested`] reason.
+    pub fn quit_with(&self, code: i32) {
+        self.quit_state.set_exit_code(code);
+        self.quit_with_reason(QuitReason::UserRequested);
+    }
+
+    /// Sends a [Quit Event](EngineEvent::Quit) carrying the provided [`QuitReason`] to trigger an
+    /// engine shutdown.
+    ///
+    /// The exit code is left as-is.
+    pub fn quit_with_reason(&self, reason: QuitReason) {
+        self.quit_state.set_has_quit();
         self.event_sender
-            .send_event(Box::from(EngineEvent::Quit))
+            .send_event(Box::from(EngineEvent::Quit(reason)))
             .ok();
     }
+
+    /// Returns `true` if the engine has been asked to quit.
+    ///
+    /// This is useful for last-minute work, such as flushing save data, before the engine shuts
+    /// down.
+    pub fn has_quit(&self) -> bool {
+        self.quit_state.has_quit()
+    }
+
+    /// Returns the exit code recorded by the last call to [`Context::quit_with()`].
+    ///
+    /// Returns `0` if no exit code was recorded.
+    pub fn exit_code(&self) -> i32 {
+        self.quit_state.exit_code()
+    }
+
+    /// Returns a shared handle to the engine's [`QuitState`].
+    ///
+    /// Unlike [`Context::has_quit()`], and [`Context::exit_code()`], the handle can still be read
+    /// once the `Context` has been dropped.
+    pub fn quit_state(&self) -> Arc<QuitState> {
+        self.quit_state.clone()
+    }
+}
+
+/// A guard which dereferences to the [`Context`], and removes a scoped resource from it when
+/// dropped.
+///
+/// See [`Context::with_scoped_resource()`].
+pub struct ScopedContext<'a, T: Resource> {
+    context: &'a mut Context,
+    previous: Option<T>,
+}
+
+impl<T: Resource> ScopedContext<'_, T> {
+    /// Borrows the scoped resource.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource was removed, or is already mutably borrowed.
+    pub fn resource(&self) -> impl Deref<Target = T> + '_ {
+        self.context
+            .resources
+            .get::<T>()
+            .expect("The scoped resource should exist, and not be mutably borrowed")
+    }
+
+    /// Mutably borrows the scoped resource.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource was removed, or is already borrowed.
+    pub fn resource_mut(&self) -> impl DerefMut<Target = T> + '_ {
+        self.context
+            .resources
+            .get_mut::<T>()
+            .expect("The scoped resource should exist, and not be borrowed")
+    }
+}
+
+impl<T: Resource> Deref for ScopedContext<'_, T> {
+    type Target = Context;
+
+    fn deref(&self) -> &Self::Target {
+        self.context
+    }
+}
+
+impl<T: Resource> DerefMut for ScopedContext<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.context
+    }
+}
+
+impl<T: Resource> Drop for ScopedContext<'_, T> {
+    fn drop(&mut self) {
+        self.context.resources.remove::<T>();
+        if let Some(previous) = self.previous.take() {
+            self.context.resources.insert(previous);
+        }
+    }
+}
+
+impl Drop for Context {
+    fn drop(&mut self) {
+        let mut plugins = std::mem::take(&mut self.plugins);
+        for plugin in plugins.iter_mut().rev() {
+            plugin.teardown(self);
+        }
+    }
 }
 
 #[cfg(test)]
 mod context_tests {
+    use crate::resources::ResourceAlreadyExistsError;
+
     #[test]
     fn should_have_accessors() {
         let (_, mut context) = crate::init().build().unwrap();
@@ -47,4 +250,234 @@ mod context_tests {
             let _mut_resources = context.resources_mut();
         }
     }
+
+    struct ScopedResource(i32);
+
+    #[test]
+    fn should_remove_scoped_resources_when_the_scope_ends() {
+        let (_, mut context) = crate::init().build().unwrap();
+
+        {
+            let scope = context.with_scoped_resource(ScopedResource(1));
+            assert_eq!(scope.resources().get::<ScopedResource>().unwrap().0, 1);
+        }
+
+        assert!(context.resources().get::<ScopedResource>().is_err());
+    }
+
+    #[test]
+    fn should_restore_shadowed_resources_when_the_scope_ends() {
+        let (_, mut context) = crate::init()
+            .with_resource(ScopedResource(0))
+            .build()
+            .unwrap();
+
+        {
+            let scope = context.with_scoped_resource(ScopedResource(1));
+            assert_eq!(scope.resources().get::<ScopedResource>().unwrap().0, 1);
+        }
+
+        assert_eq!(context.resources().get::<ScopedResource>().unwrap().0, 0);
+    }
+
+    #[test]
+    fn should_record_the_exit_code() {
+        let (_, context) = crate::init().build().unwrap();
+
+        context.quit_with(2);
+
+        assert_eq!(context.exit_code(), 2);
+    }
+
+    #[test]
+    fn should_use_an_exit_code_of_0_by_default() {
+        let (_, context) = crate::init().build().unwrap();
+
+        context.quit();
+
+        assert_eq!(context.exit_code(), 0);
+    }
+
+    #[test]
+    fn should_report_when_the_engine_has_quit() {
+        let (_, context) = crate::init().build().unwrap();
+        assert!(!context.has_quit());
+
+        context.quit();
+
+        assert!(context.has_quit());
+    }
+
+    #[test]
+    fn should_add_resources_which_do_not_exist_yet() {
+        let (_, mut context) = crate::init().build().unwrap();
+
+        let previous = context.add_or_replace(ScopedResource(1));
+
+        assert!(previous.is_none());
+        assert_eq!(context.resources().get::<ScopedResource>().unwrap().0, 1);
+    }
+
+    #[test]
+    fn should_replace_existing_resources() {
+        let (_, mut context) = crate::init()
+            .with_resource(ScopedResource(0))
+            .build()
+            .unwrap();
+
+        let previous = context.add_or_replace(ScopedResource(1));
+
+        assert_eq!(previous.unwrap().0, 0);
+        assert_eq!(context.resources().get::<ScopedResource>().unwrap().0, 1);
+    }
+
+    #[test]
+    fn should_send_the_quit_reason() {
+        use crate::events::*;
+
+        let (mut event_loop, context) = crate::init().build().unwrap();
+
+        context.quit_with_reason(QuitReason::Error("Something went wrong".to_string()));
+        let event = event_loop.next_event().unwrap();
+
+        assert_eq!(
+            *event.downcast::<EngineEvent>().unwrap(),
+            EngineEvent::Quit(QuitReason::Error("Something went wrong".to_string()))
+        );
+        assert!(context.has_quit());
+    }
+
+    struct OtherResource(i32);
+
+    #[test]
+    fn should_borrow_two_resources_mutably() {
+        let (_, context) = crate::init()
+            .with_resource(ScopedResource(1))
+            .with_resource(OtherResource(2))
+            .build()
+            .unwrap();
+
+        {
+            let (mut a, mut b) = context
+                .borrow_mut_two::<ScopedResource, OtherResource>()
+                .unwrap();
+            std::mem::swap(&mut a.0, &mut b.0);
+        }
+
+        assert_eq!(context.resources().get::<ScopedResource>().unwrap().0, 2);
+        assert_eq!(context.resources().get::<OtherResource>().unwrap().0, 1);
+    }
+
+    #[test]
+    fn should_not_borrow_two_resources_if_one_is_missing_or_borrowed() {
+        let (_, context) = crate::init()
+            .with_resource(ScopedResource(1))
+            .build()
+            .unwrap();
+        assert!(context
+            .borrow_mut_two::<ScopedResource, OtherResource>()
+            .is_none());
+
+        let (_, context) = crate::init()
+            .with_resource(ScopedResource(1))
+            .with_resource(OtherResource(2))
+            .build()
+            .unwrap();
+        let _borrowed = context.resources().get::<OtherResource>().unwrap();
+        assert!(context
+            .borrow_mut_two::<ScopedResource, OtherResource>()
+            .is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_borrow_the_same_resource_twice() {
+        let (_, context) = crate::init()
+            .with_resource(ScopedResource(1))
+            .build()
+            .unwrap();
+
+        let _ = context.borrow_mut_two::<ScopedResource, ScopedResource>();
+    }
+
+    #[test]
+    fn should_run_closures_with_resources() {
+        let (_, context) = crate::init()
+            .with_resource(ScopedResource(1))
+            .build()
+            .unwrap();
+
+        let previous = context.with_resource_mut(|resource: &mut ScopedResource| {
+            resource.0 += 1;
+            resource.0 - 1
+        });
+        let current = context.with_resource(|resource: &ScopedResource| resource.0);
+
+        assert_eq!(previous, Some(1));
+        assert_eq!(current, Some(2));
+    }
+
+    #[test]
+    fn should_not_run_closures_with_missing_resources() {
+        let (_, context) = crate::init().build().unwrap();
+
+        assert!(context
+            .with_resource(|_: &ScopedResource| panic!("The resource does not exist"))
+            .is_none());
+        assert!(context
+            .with_resource_mut(|_: &mut ScopedResource| panic!("The resource does not exist"))
+            .is_none());
+    }
+
+    #[derive(Default)]
+    struct DefaultResource(i32);
+
+    #[test]
+    fn should_add_default_resources() {
+        let (_, mut context) = crate::init().build().unwrap();
+
+        context.add_default::<DefaultResource>().unwrap();
+
+        assert_eq!(context.resources().get::<DefaultResource>().unwrap().0, 0);
+    }
+
+    #[test]
+    fn should_not_add_default_resources_which_already_exist() {
+        let (_, mut context) = crate::init()
+            .with_resource(DefaultResource(1))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            context.add_default::<DefaultResource>(),
+            Err(ResourceAlreadyExistsError)
+        );
+        assert_eq!(context.resources().get::<DefaultResource>().unwrap().0, 1);
+    }
+
+    #[test]
+    fn should_borrow_the_scoped_resource_through_the_scope() {
+        let (_, mut context) = crate::init().build().unwrap();
+        let scope = context.with_scoped_resource(ScopedResource(1));
+
+        scope.resource_mut().0 += 1;
+
+        assert_eq!(scope.resource().0, 2);
+    }
+
+    #[test]
+    fn should_restore_shadowed_resources_even_if_the_scoped_resource_was_removed() {
+        let (_, mut context) = crate::init()
+            .with_resource(ScopedResource(0))
+            .build()
+            .unwrap();
+
+        {
+            let mut scope = context.with_scoped_resource(ScopedResource(1));
+            assert_eq!(scope.resource().0, 1);
+            scope.resources_mut().remove::<ScopedResource>();
+        }
+
+        assert_eq!(context.resources().get::<ScopedResource>().unwrap().0, 0);
+    }
 }
@@ -6,7 +6,7 @@ use shared_resources::Resource;
 
 use crate::plugins::*;
 use crate::prelude::*;
-use crate::resources::Resources;
+use crate::resources::{ResourceAlreadyExistsError, Resources};
 
 /// Provides the type-states used by the [`EngineBuilder`].
 pub mod state {
@@ -20,61 +20,182 @@ pub mod state {
 /// Provides a common interface for configuring the [`Engine`].
 pub struct EngineBuilder<State> {
     resources: Resources,
+    has_resources: bool,
+    resource_collision: bool,
     plugin_loader: PluginLoader,
+    #[cfg(feature = "logging")]
+    logging_level: Option<log::LevelFilter>,
     _state: PhantomData<State>,
 }
 
 /// Provides methods only available during the [`Setup`] state.
 impl EngineBuilder<state::Setup> {
     pub fn with_plugin<T: Plugin + 'static>(&mut self, plugin: T) -> &mut Self {
-        self.plugin_loader.add_plugin(Box::from(plugin));
+        self.plugin_loader.add_plugin(plugin);
+        self
+    }
+
+    /// Adds an [`AsyncPlugin`], which is loaded along with the other plugins.
+    ///
+    /// The engine blocks until the plugin's [`AsyncPlugin::load()`] future has completed.
+    pub fn with_async_plugin<T: AsyncPlugin + 'static>(&mut self, plugin: T) -> &mut Self {
+        self.plugin_loader.add_async_plugin(plugin);
+        self
+    }
+
+    /// Sets what happens when the same [`Plugin`] type is added more than once.
+    ///
+    /// Defaults to [`DuplicatePluginPolicy::Skip`].
+    pub fn with_duplicate_plugin_policy(&mut self, policy: DuplicatePluginPolicy) -> &mut Self {
+        self.plugin_loader.set_duplicate_policy(policy);
+        self
+    }
+
+    /// Adds every resource from a prebuilt [`Resources`] container.
+    ///
+    /// The container is only adopted as-is if no resources were added to the builder before.
+    /// [`Resources`] can't be merged, so if the builder already has resources, the container is
+    /// dropped, and [`EngineBuilder::build()`] fails with [`PluginError::ResourceCollision`].
+    /// Resources added after this call are inserted into the adopted container as usual.
+    pub fn with_resources(&mut self, resources: Resources) -> &mut Self {
+        if self.has_resources {
+            self.resource_collision = true;
+        } else {
+            self.resources = resources;
+            self.has_resources = true;
+        }
+        self
+    }
+
+    /// Sets the maximum [log level](log::LevelFilter) used once the engine is built.
+    #[cfg(feature = "logging")]
+    pub fn with_logging_level(&mut self, level: log::LevelFilter) -> &mut Self {
+        self.logging_level = Some(level);
         self
     }
 
     /// Consume the builder, and return the [`Engine`] created from it.
-    pub fn build(&mut self) -> Result<Engine, String> {
+    pub fn build(&mut self) -> Result<Engine, PluginError> {
+        #[cfg(feature = "logging")]
+        if let Some(level) = self.logging_level {
+            log::set_max_level(level);
+        }
+        if self.resource_collision {
+            return Err(PluginError::ResourceCollision(ResourceAlreadyExistsError));
+        }
         let (mut engine_builder, mut plugin_loader) = self.start_build();
         let event_loop = EventLoop::new();
-        engine_builder.with_resource(event_loop.event_sender().clone());
+        engine_builder.insert_default_resources(&event_loop);
         plugin_loader.load_plugins(&mut engine_builder)?;
         let context = Context {
             resources: std::mem::take(&mut engine_builder.resources),
             event_sender: event_loop.event_sender().clone(),
+            plugins: plugin_loader.into_plugins(),
+            quit_state: event_loop.quit_state().clone(),
         };
         Ok((event_loop, context))
     }
 
+    /// Load the builder's plugins without building the [`Engine`].
+    ///
+    /// Unlike [`EngineBuilder::build()`], loading does not stop at the first failing [`Plugin`].
+    /// Every error is collected, and reported together, which makes it easier to diagnose setups
+    /// with many plugins.
+    ///
+    /// Every plugin which loaded successfully is [torn down](Plugin::teardown()) afterwards, and
+    /// the builder is left as it was, so it can still be built.  Plugins are expected to undo
+    /// their changes to the resources in their teardown, anything left behind stays on the
+    /// builder.
+    pub fn validate(&mut self) -> Result<(), Vec<PluginError>> {
+        let (mut engine_builder, mut plugin_loader) = self.start_build();
+        let event_loop = EventLoop::new();
+        engine_builder.insert_default_resources(&event_loop);
+        let (loaded, mut errors) = plugin_loader.validate(&mut engine_builder);
+        if self.resource_collision {
+            errors.insert(
+                0,
+                PluginError::ResourceCollision(ResourceAlreadyExistsError),
+            );
+        }
+        let mut context = Context {
+            resources: std::mem::take(&mut engine_builder.resources),
+            event_sender: event_loop.event_sender().clone(),
+            plugins: Vec::new(),
+            quit_state: event_loop.quit_state().clone(),
+        };
+        plugin_loader.teardown_plugins(&loaded, &mut context);
+        context.resources.remove::<MainEventSender>();
+        context.resources.remove::<EngineEventSender>();
+        self.resources = std::mem::take(&mut context.resources);
+        self.plugin_loader = plugin_loader;
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
     fn start_build(&mut self) -> (EngineBuilder<state::PluginLoad>, PluginLoader) {
         let plugin_loader = std::mem::take(&mut self.plugin_loader);
         let resources = std::mem::take(&mut self.resources);
         let engine_builder = EngineBuilder::<state::PluginLoad> {
             resources,
+            has_resources: true,
+            resource_collision: false,
             plugin_loader: PluginLoader::default(),
+            #[cfg(feature = "logging")]
+            logging_level: None,
             _state: PhantomData,
         };
         (engine_builder, plugin_loader)
     }
 }
 
+impl EngineBuilder<state::PluginLoad> {
+    fn insert_default_resources(&mut self, event_loop: &EventLoop) {
+        self.with_resource(event_loop.event_sender().clone());
+        self.with_resource(EngineEventSender::new(
+            event_loop.event_sender().clone(),
+            event_loop.quit_state().clone(),
+        ));
+    }
+}
+
 /// Provides methods available during any state.
 impl<State> EngineBuilder<State> {
     pub(crate) fn new() -> EngineBuilder<state::Setup> {
         EngineBuilder::<state::Setup> {
             resources: Resources::default(),
+            has_resources: false,
+            resource_collision: false,
             plugin_loader: PluginLoader::new(),
+            #[cfg(feature = "logging")]
+            logging_level: None,
             _state: PhantomData,
         }
     }
 
     pub fn with_resource<T: Resource>(&mut self, resource: T) -> &mut Self {
         self.resources.insert(resource);
+        self.has_resources = true;
         self
     }
+
+    /// Adds the [default](Default) value of a resource.
+    ///
+    /// This is a shortcut for calling [`EngineBuilder::with_resource()`] with `T::default()`.
+    pub fn with_default_resource<T: Resource + Default>(&mut self) -> &mut Self {
+        self.with_resource(T::default())
+    }
 }
 
 #[cfg(test)]
 mod engin_builder_tests {
-    use crate::{events::MainEventSender, plugins::MockPlugin};
+    use crate::{
+        events::MainEventSender,
+        plugins::{MockPlugin, PluginError},
+        resources::Resources,
+    };
 
     #[test]
     fn should_add_resources() {
@@ -89,10 +210,34 @@ mod engin_builder_tests {
     #[test]
     fn should_add_plugins() {
         let mut plugin = MockPlugin::new();
-        plugin.expect_load().once().return_const(Ok(()));
+        plugin.expect_load().once().returning(|_| Ok(()));
+        plugin.expect_dependencies().return_const(Vec::new());
+        plugin.expect_teardown().once().return_const(());
         let _engine = crate::init().with_plugin(plugin).build().unwrap();
     }
 
+    #[test]
+    fn should_add_every_resource_from_a_prebuilt_container() {
+        let mut resources = Resources::default();
+        resources.insert(0_i32);
+        resources.insert("Hello, World!");
+
+        let (_event_loop, context) = crate::init().with_resources(resources).build().unwrap();
+
+        assert_eq!(*context.resources().get::<i32>().unwrap(), 0);
+        assert_eq!(*context.resources().get::<&str>().unwrap(), "Hello, World!");
+    }
+
+    #[test]
+    fn should_fail_to_build_if_resources_are_added_to_a_builder_with_resources() {
+        let result = crate::init()
+            .with_resource(0_u32)
+            .with_resources(Resources::default())
+            .build();
+
+        assert!(matches!(result, Err(PluginError::ResourceCollision(_))));
+    }
+
     #[test]
     fn should_add_event_sender_resource_by_default() {
         let (_event_loop, context) = crate::init().build().unwrap();
@@ -101,4 +246,25 @@ mod engin_builder_tests {
             .get_mut::<MainEventSender>()
             .expect("No event sender was added.");
     }
+
+    #[cfg(feature = "logging")]
+    #[test]
+    fn should_set_the_logging_level() {
+        let _engine = crate::init()
+            .with_logging_level(log::LevelFilter::Trace)
+            .build()
+            .unwrap();
+
+        assert_eq!(log::max_level(), log::LevelFilter::Trace);
+    }
+
+    #[test]
+    fn should_add_default_resources() {
+        let (_event_loop, context) = crate::init()
+            .with_default_resource::<i32>()
+            .build()
+            .unwrap();
+
+        assert_eq!(*context.resources().get::<i32>().unwrap(), 0);
+    }
 }
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use crate::events::*;
+use crate::QuitState;
+
+/// Provides a wrapper around the [`MainEventSender`], which boxes events for you.
+///
+/// The engine inserts one of these as a resource, alongside the raw [`MainEventSender`].
+///
+/// # Examples
+///
+/// ```
+/// # use wolf_engine_core as wolf_engine;
+/// # use wolf_engine::prelude::*;
+/// # use wolf_engine::events::EngineEventSender;
+/// #
+/// #[derive(Event, Debug)]
+/// struct MyEvent;
+///
+/// # let (mut event_loop, context) = wolf_engine::init().build().unwrap();
+/// #
+/// let event_sender = context.resources().get::<EngineEventSender>().unwrap();
+/// event_sender.send(MyEvent).unwrap();
+/// ```
+#[derive(Clone)]
+pub struct EngineEventSender {
+    inner: MainEventSender,
+    quit_state: Arc<QuitState>,
+}
+
+impl EngineEventSender {
+    /// Creates a new wrapper around the provided [`MainEventSender`].
+    ///
+    /// The [`QuitState`] should be the one shared by the engine's
+    /// [`Context`](crate::Context), see [`Context::quit_state()`](crate::Context::quit_state()).
+    pub fn new(inner: MainEventSender, quit_state: Arc<QuitState>) -> Self {
+        Self { inner, quit_state }
+    }
+
+    /// Boxes, and sends the provided [`Event`].
+    pub fn send<E: Event>(&self, event: E) -> Result<(), ReceiverDroppedError> {
+        self.inner.send_event(Box::from(event))
+    }
+
+    /// Sends a [Quit Event](EngineEvent::Quit) to trigger an engine shutdown, with a
+    /// [`QuitReason::UserRequested`] reason.
+    ///
+    /// This works the same way as [`Context::quit()`](crate::Context::quit()), the engine is
+    /// marked as having quit, with an exit code of `0`.
+    pub fn quit(&self) {
+        self.quit_state.set_exit_code(0);
+        self.quit_state.set_has_quit();
+        self.send(EngineEvent::Quit(QuitReason::UserRequested)).ok();
+    }
+
+    /// Returns a reference to the underlying [`MainEventSender`].
+    pub fn inner(&self) -> &MainEventSender {
+        &self.inner
+    }
+}
+
+#[cfg(test)]
+mod engine_event_sender_tests {
+    use super::*;
+
+    #[derive(Event, Debug, PartialEq)]
+    struct TestEvent(i32);
+
+    #[test]
+    fn should_box_and_send_events() {
+        let (mut event_loop, context) = crate::init().build().unwrap();
+        let event_sender = context.resources().get::<EngineEventSender>().unwrap();
+
+        event_sender.send(TestEvent(42)).unwrap();
+        let event = event_loop.next_event().unwrap();
+
+        assert_eq!(*event.downcast::<TestEvent>().unwrap(), TestEvent(42));
+    }
+
+    #[test]
+    fn should_send_quit_events() {
+        let (mut event_loop, context) = crate::init().build().unwrap();
+        let event_sender = context.resources().get::<EngineEventSender>().unwrap();
+
+        event_sender.quit();
+        let event = event_loop.next_event().unwrap();
+
+        assert_eq!(
+            *event.downcast::<EngineEvent>().unwrap(),
+            EngineEvent::Quit(QuitReason::UserRequested)
+        );
+        assert!(event_loop.next_event().is_none());
+    }
+
+    #[test]
+    fn should_mark_the_context_as_having_quit() {
+        let (_event_loop, context) = crate::init().build().unwrap();
+        let event_sender = context
+            .resources()
+            .get::<EngineEventSender>()
+            .unwrap()
+            .clone();
+
+        event_sender.quit();
+
+        assert!(context.has_quit());
+        assert_eq!(context.exit_code(), 0);
+    }
+}
@@ -4,11 +4,26 @@ use crate::events::Event;
 #[non_exhaustive]
 #[derive(Event, Debug, PartialEq, Eq)]
 pub enum EngineEvent {
-    /// Emitted when the engine should quit.
-    Quit,
+    /// Emitted when the engine should quit, along with the reason why.
+    Quit(QuitReason),
 
     /// Indicates the end of a frame.
     ///
     /// `EventsCleared` should be emitted only after all other events have been processed.
     EventsCleared,
 }
+
+/// Describes why the engine was asked to [quit](EngineEvent::Quit).
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum QuitReason {
+    /// The user, or the game asked to quit normally.
+    #[default]
+    UserRequested,
+
+    /// The engine is quitting because of an error.
+    Error(String),
+
+    /// The engine is quitting so it can be restarted.
+    Restart,
+}
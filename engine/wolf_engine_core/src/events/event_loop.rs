@@ -1,5 +1,9 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
 use crate::events::mpsc::{MpscEventReceiver, MpscEventSender};
 use crate::events::*;
+use crate::QuitState;
 
 /// Provides a way to retrieve events from the [`Context`](crate::Context).
 ///
@@ -37,6 +41,7 @@ pub struct EventLoop {
     event_receiver: MpscEventReceiver<EventBox>,
     event_sender: MpscEventSender<EventBox>,
     has_quit: bool,
+    quit_state: Arc<QuitState>,
 }
 
 impl EventLoop {
@@ -46,16 +51,60 @@ impl EventLoop {
             event_sender,
             event_receiver,
             has_quit: false,
+            quit_state: Arc::new(QuitState::new()),
         }
     }
 
+    /// Returns the [`QuitState`] shared with the [`Context`](crate::Context).
+    pub(crate) fn quit_state(&self) -> &Arc<QuitState> {
+        &self.quit_state
+    }
+
     pub fn event_sender(&self) -> &MpscEventSender<EventBox> {
         &self.event_sender
     }
 
+    /// Returns `true` once the [`EngineEvent::Quit`] event has been processed.
+    ///
+    /// After this point, the event loop will not emit any more events.  This makes it possible to
+    /// tell "no events this frame" apart from "shutting down."
+    pub fn has_quit(&self) -> bool {
+        self.has_quit
+    }
+
+    /// Returns the next queued event, sleeping for up to `timeout` while the queue is empty.
+    ///
+    /// Unlike [`EventReceiver::next_event()`], this method does not emit
+    /// [`EngineEvent::EventsCleared`] when the queue is empty.  Instead, it returns `None` if no
+    /// event was queued before the timeout.  `None` is also returned once the engine has quit.
+    ///
+    /// This is not a blocking receive.  The underlying event queue has none, so the queue is
+    /// polled roughly once every millisecond, with the thread sleeping in between.  Event senders
+    /// are not `Send`, so events can only be queued from the thread running this method, which
+    /// means it's mostly useful for idling until the next frame, rather than waiting on other
+    /// threads.
+    pub fn next_event_timeout(&mut self, timeout: Duration) -> Option<EventBox> {
+        let start = Instant::now();
+        while !self.has_quit {
+            if let Some(event) = self.event_receiver.next_event() {
+                if let Some(downcast) = event.downcast_ref::<EngineEvent>() {
+                    self.handle_event(downcast);
+                }
+                return Some(event);
+            }
+            let elapsed = start.elapsed();
+            if elapsed >= timeout {
+                break;
+            }
+            std::thread::sleep((timeout - elapsed).min(Duration::from_millis(1)));
+        }
+        None
+    }
+
     fn handle_event(&mut self, event: &EngineEvent) {
-        if *event == EngineEvent::Quit {
+        if let EngineEvent::Quit(_) = event {
             self.has_quit = true;
+            self.quit_state.set_has_quit();
         }
     }
 }
@@ -96,13 +145,13 @@ mod event_loop_tests {
             }
         }
 
-        assert!(event_loop.has_quit);
+        assert!(event_loop.has_quit());
         assert_eq!(updates, 3);
     }
 
     fn process_event(event: EngineEvent, context: &mut Context, updates: &mut i32) {
         match event {
-            EngineEvent::Quit => (),
+            EngineEvent::Quit(_) => (),
             EngineEvent::EventsCleared => {
                 if *updates == 3 {
                     context.quit();
@@ -135,10 +184,70 @@ mod event_loop_tests {
         while let Some(event) = event_loop.next_event() {
             if let Some(engine_event) = event.downcast_ref::<EngineEvent>() {
                 match engine_event {
-                    EngineEvent::Quit => context.quit(),
+                    EngineEvent::Quit(_) => context.quit(),
                     EngineEvent::EventsCleared => context.quit(),
                 }
             }
         }
     }
+
+    #[derive(Event, Debug)]
+    struct TestEvent;
+
+    #[test]
+    #[timeout(100)]
+    fn next_event_timeout_should_return_queued_events() {
+        let (mut event_loop, context) = crate::init().build().unwrap();
+        context
+            .event_sender()
+            .send_event(Box::from(TestEvent))
+            .unwrap();
+
+        let event = event_loop
+            .next_event_timeout(std::time::Duration::from_secs(10))
+            .unwrap();
+
+        assert!(event.downcast_ref::<TestEvent>().is_some());
+    }
+
+    #[test]
+    #[timeout(100)]
+    fn next_event_timeout_should_return_none_after_the_timeout() {
+        let (mut event_loop, _context) = crate::init().build().unwrap();
+        let timeout = std::time::Duration::from_millis(20);
+        let start = std::time::Instant::now();
+
+        assert!(event_loop.next_event_timeout(timeout).is_none());
+        assert!(start.elapsed() >= timeout);
+    }
+
+    #[test]
+    fn should_report_when_the_quit_event_has_been_processed() {
+        let (mut event_loop, context) = crate::init().build().unwrap();
+        assert!(!event_loop.has_quit());
+
+        context.quit();
+        assert!(
+            !event_loop.has_quit(),
+            "The quit event was not processed yet"
+        );
+        let _ = event_loop.next_event();
+
+        assert!(event_loop.has_quit());
+        assert!(event_loop.next_event().is_none());
+    }
+
+    #[test]
+    fn should_mark_the_context_as_having_quit_when_processing_quit_events() {
+        let (mut event_loop, context) = crate::init().build().unwrap();
+        context
+            .event_sender()
+            .send_event(Box::from(EngineEvent::Quit(QuitReason::UserRequested)))
+            .unwrap();
+        assert!(!context.has_quit());
+
+        let _ = event_loop.next_event();
+
+        assert!(context.has_quit());
+    }
 }
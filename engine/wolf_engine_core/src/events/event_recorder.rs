@@ -0,0 +1,105 @@
+use crate::events::{EventReceiver, EventSender, ReceiverDroppedError};
+
+/// Provides an [`EventReceiver`] wrapper which records every event it receives.
+///
+/// The recording can later be [replayed](EventRecorder::replay_into()) into another queue, which
+/// is useful for reproducing bugs in deterministic tests.
+///
+/// # Examples
+///
+/// ```
+/// # use wolf_engine_core::events::*;
+/// #
+/// let (sender, receiver) = mpsc::event_queue();
+/// let mut recorder = EventRecorder::new(receiver);
+/// sender.send_event(1).unwrap();
+/// sender.send_event(2).unwrap();
+/// while recorder.next_event().is_some() {}
+///
+/// let (replay_sender, mut replay_receiver) = mpsc::event_queue();
+/// recorder.replay_into(&replay_sender).unwrap();
+///
+/// assert_eq!(replay_receiver.next_event(), Some(1));
+/// assert_eq!(replay_receiver.next_event(), Some(2));
+/// ```
+pub struct EventRecorder<E, R>
+where
+    E: Clone,
+    R: EventReceiver<E>,
+{
+    receiver: R,
+    recording: Vec<E>,
+}
+
+impl<E, R> EventRecorder<E, R>
+where
+    E: Clone,
+    R: EventReceiver<E>,
+{
+    /// Creates a new recorder wrapping the provided [`EventReceiver`].
+    pub fn new(receiver: R) -> Self {
+        Self {
+            receiver,
+            recording: Vec::new(),
+        }
+    }
+
+    /// Returns the events recorded so far, in the order they were received.
+    pub fn recording(&self) -> &[E] {
+        &self.recording
+    }
+
+    /// Re-sends every recorded event, in order, through the provided [`EventSender`].
+    pub fn replay_into<S: EventSender<E>>(&self, sender: &S) -> Result<(), ReceiverDroppedError> {
+        for event in self.recording.iter().cloned() {
+            sender.send_event(event)?;
+        }
+        Ok(())
+    }
+
+    /// Consumes the recorder, and returns the wrapped [`EventReceiver`].
+    pub fn into_inner(self) -> R {
+        self.receiver
+    }
+}
+
+impl<E, R> EventReceiver<E> for EventRecorder<E, R>
+where
+    E: Clone,
+    R: EventReceiver<E>,
+{
+    fn next_event(&mut self) -> Option<E> {
+        let event = self.receiver.next_event()?;
+        self.recording.push(event.clone());
+        Some(event)
+    }
+}
+
+#[cfg(test)]
+mod event_recorder_tests {
+    use super::*;
+    use crate::events::mpsc;
+
+    #[test]
+    fn should_replay_recorded_events_in_order() {
+        let (sender, receiver) = mpsc::event_queue();
+        let mut recorder = EventRecorder::new(receiver);
+        for event in ["a", "b", "c"] {
+            sender.send_event(event).unwrap();
+        }
+        let mut received = Vec::new();
+        while let Some(event) = recorder.next_event() {
+            received.push(event);
+        }
+
+        let (replay_sender, mut replay_receiver) = mpsc::event_queue();
+        recorder.replay_into(&replay_sender).unwrap();
+        let mut replayed = Vec::new();
+        while let Some(event) = replay_receiver.next_event() {
+            replayed.push(event);
+        }
+
+        assert_eq!(recorder.recording(), ["a", "b", "c"]);
+        assert_eq!(replayed, received);
+    }
+}
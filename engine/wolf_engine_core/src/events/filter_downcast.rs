@@ -0,0 +1,88 @@
+use std::marker::PhantomData;
+
+use crate::events::{Event, EventBox};
+
+/// Provides extra methods for iterators over [`EventBoxes`](EventBox).
+pub trait EventIteratorExt: Iterator<Item = EventBox> + Sized {
+    /// Returns an iterator which only yields events of type `E`, already downcast, and unboxed.
+    ///
+    /// Events of any other type are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use wolf_engine_core::events::*;
+    /// #
+    /// #[derive(Event, Debug, PartialEq)]
+    /// struct Jump;
+    ///
+    /// #[derive(Event, Debug)]
+    /// struct Crouch;
+    ///
+    /// let events: Vec<EventBox> = vec![Box::from(Jump), Box::from(Crouch), Box::from(Jump)];
+    /// let jumps: Vec<Jump> = events.into_iter().filter_downcast::<Jump>().collect();
+    ///
+    /// assert_eq!(jumps, vec![Jump, Jump]);
+    /// ```
+    fn filter_downcast<E: Event>(self) -> FilterDowncast<Self, E> {
+        FilterDowncast {
+            inner: self,
+            _event: PhantomData,
+        }
+    }
+}
+
+impl<I: Iterator<Item = EventBox>> EventIteratorExt for I {}
+
+/// An iterator which yields only events of type `E`.
+///
+/// See [`EventIteratorExt::filter_downcast()`].
+pub struct FilterDowncast<I, E> {
+    inner: I,
+    _event: PhantomData<E>,
+}
+
+impl<I, E> Iterator for FilterDowncast<I, E>
+where
+    I: Iterator<Item = EventBox>,
+    E: Event,
+{
+    type Item = E;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .by_ref()
+            .find_map(|event| event.downcast::<E>().ok())
+            .map(|event| *event)
+    }
+}
+
+#[cfg(test)]
+mod filter_downcast_tests {
+    use super::*;
+
+    #[derive(Event, Debug, PartialEq)]
+    struct FirstEvent(i32);
+
+    #[derive(Event, Debug, PartialEq)]
+    struct SecondEvent(&'static str);
+
+    fn mixed_events() -> Vec<EventBox> {
+        vec![
+            Box::from(FirstEvent(1)),
+            Box::from(SecondEvent("a")),
+            Box::from(SecondEvent("b")),
+            Box::from(FirstEvent(2)),
+        ]
+    }
+
+    #[test]
+    fn should_only_yield_the_requested_type() {
+        let first_events: Vec<FirstEvent> = mixed_events().into_iter().filter_downcast().collect();
+        let second_events: Vec<SecondEvent> =
+            mixed_events().into_iter().filter_downcast().collect();
+
+        assert_eq!(first_events, vec![FirstEvent(1), FirstEvent(2)]);
+        assert_eq!(second_events, vec![SecondEvent("a"), SecondEvent("b")]);
+    }
+}
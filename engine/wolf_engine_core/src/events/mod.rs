@@ -9,10 +9,18 @@ use downcast_rs::*;
 pub use generic_event_queue::*;
 pub use wolf_engine_codegen::Event;
 
+mod engine_event_sender;
+pub use engine_event_sender::*;
 mod event;
 pub use event::*;
 mod event_loop;
 pub use event_loop::*;
+mod event_recorder;
+pub use event_recorder::*;
+mod filter_downcast;
+pub use filter_downcast::*;
+mod priority_event_queue;
+pub use priority_event_queue::*;
 
 /// An alias to the main [`EventSender`] type associated with the
 /// [`EventLoop`].
@@ -36,10 +44,36 @@ mod event_tests {
     #[derive(Event, Debug)]
     struct TestEvent(&'static str);
 
+    #[derive(Event, Debug)]
+    struct GenericTestEvent<T>(T)
+    where
+        T: Clone;
+
+    #[derive(Event, Debug)]
+    struct BorrowedTestEvent<'a>(&'a str);
+
     #[test_case(&TestEvent("Hello, World!"))]
     fn should_auto_impl_event(event: &dyn Event) {
         if let Some(event) = event.downcast_ref::<TestEvent>() {
             assert_eq!(event.0, "Hello, World!");
         }
     }
+
+    #[test]
+    fn should_auto_impl_event_for_generic_types() {
+        let event: EventBox = Box::from(GenericTestEvent(42));
+
+        let event = event.downcast::<GenericTestEvent<i32>>().unwrap();
+
+        assert_eq!(event.0, 42);
+    }
+
+    #[test]
+    fn should_auto_impl_event_for_types_with_lifetimes() {
+        let event: EventBox = Box::from(BorrowedTestEvent("Hello, World!"));
+
+        let event = event.downcast::<BorrowedTestEvent<'static>>().unwrap();
+
+        assert_eq!(event.0, "Hello, World!");
+    }
 }
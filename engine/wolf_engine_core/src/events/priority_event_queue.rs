@@ -0,0 +1,148 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::sync::Mutex;
+
+use crate::events::{EventSender, ReceiverDroppedError};
+
+/// Provides an event queue which hands out events in order of priority.
+///
+/// Events are sent with an integer priority, and [flushed](PriorityEventQueue::flush_by_priority())
+/// highest-priority first.  Events of equal priority keep the order they were sent in.
+///
+/// Events sent through the [`EventSender`] trait use a priority of `0`.
+///
+/// # Examples
+///
+/// ```
+/// # use wolf_engine_core::events::PriorityEventQueue;
+/// #
+/// let queue = PriorityEventQueue::new();
+/// queue.send_with_priority("routine", 0);
+/// queue.send_with_priority("urgent", 10);
+///
+/// assert_eq!(queue.flush_by_priority(), vec!["urgent", "routine"]);
+/// ```
+pub struct PriorityEventQueue<E> {
+    inner: Mutex<PriorityEventQueueInner<E>>,
+}
+
+struct PriorityEventQueueInner<E> {
+    heap: BinaryHeap<PriorityEntry<E>>,
+    next_sequence: u64,
+}
+
+struct PriorityEntry<E> {
+    priority: i32,
+    sequence: u64,
+    event: E,
+}
+
+impl<E> PartialEq for PriorityEntry<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<E> Eq for PriorityEntry<E> {}
+
+impl<E> PartialOrd for PriorityEntry<E> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<E> Ord for PriorityEntry<E> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Earlier events must come out of the max-heap first, so the sequence is reversed.
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.sequence.cmp(&self.sequence))
+    }
+}
+
+impl<E> PriorityEventQueue<E> {
+    /// Creates a new, empty queue.
+    pub fn new() -> Self {
+        Self {
+            inner: Mutex::new(PriorityEventQueueInner {
+                heap: BinaryHeap::new(),
+                next_sequence: 0,
+            }),
+        }
+    }
+
+    /// Adds an event to the queue with the provided priority.
+    ///
+    /// Higher values are flushed first.
+    pub fn send_with_priority(&self, event: E, priority: i32) {
+        let mut inner = self.inner.lock().unwrap();
+        let sequence = inner.next_sequence;
+        inner.next_sequence += 1;
+        inner.heap.push(PriorityEntry {
+            priority,
+            sequence,
+            event,
+        });
+    }
+
+    /// Removes all events from the queue, and returns them highest-priority first.
+    pub fn flush_by_priority(&self) -> Vec<E> {
+        let mut inner = self.inner.lock().unwrap();
+        let mut events = Vec::with_capacity(inner.heap.len());
+        while let Some(entry) = inner.heap.pop() {
+            events.push(entry.event);
+        }
+        events
+    }
+}
+
+impl<E> Default for PriorityEventQueue<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> EventSender<E> for PriorityEventQueue<E> {
+    fn send_event(&self, event: E) -> Result<(), ReceiverDroppedError> {
+        self.send_with_priority(event, 0);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod priority_event_queue_tests {
+    use super::*;
+
+    #[test]
+    fn should_flush_high_priority_events_first() {
+        let queue = PriorityEventQueue::new();
+        queue.send_with_priority("routine", 0);
+        queue.send_with_priority("routine", 0);
+        queue.send_with_priority("quit", 100);
+
+        assert_eq!(
+            queue.flush_by_priority(),
+            vec!["quit", "routine", "routine"]
+        );
+    }
+
+    #[test]
+    fn should_keep_insertion_order_for_equal_priorities() {
+        let queue = PriorityEventQueue::new();
+        for i in 0..5 {
+            queue.send_with_priority(i, 1);
+        }
+        queue.send_event(5).unwrap();
+
+        assert_eq!(queue.flush_by_priority(), vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn should_be_empty_after_flushing() {
+        let queue = PriorityEventQueue::new();
+        queue.send_with_priority((), 0);
+        queue.flush_by_priority();
+
+        assert!(queue.flush_by_priority().is_empty());
+    }
+}
@@ -40,7 +40,7 @@
 //! #           context.quit();
 //!         }
 //!         // Shut down the game.
-//!         EngineEvent::Quit => println!("Quit event received.  Goodbye!"),
+//!         EngineEvent::Quit(_) => println!("Quit event received.  Goodbye!"),
 //!         _ => (),
 //!     }
 //! }
@@ -55,6 +55,9 @@ pub use context::*;
 pub mod engine_builder;
 pub mod events;
 pub mod plugins;
+mod quit_state;
+pub use quit_state::*;
+pub mod resources;
 
 use engine_builder::state::Setup;
 use engine_builder::EngineBuilder;
@@ -63,13 +66,6 @@ use events::EventLoop;
 /// Represents the [`EventLoop`]-[`Context`] pair that makes up "the engine."
 pub type Engine = (EventLoop, Context);
 
-/// Provides a shared resource container which is thread-safe, and lock-free
-///
-/// Wolf Engine re-exports [`shared_resources`], see the original crate for details.
-pub mod resources {
-    pub use shared_resources::*;
-}
-
 #[doc(hidden)]
 pub mod prelude {
     pub use super::*;
@@ -1,12 +1,102 @@
 //! Provides a plugin system for the engine.
 
+use std::any::TypeId;
+use std::fmt::{Display, Formatter};
+
 use crate::engine_builder::{state::PluginLoad, EngineBuilder};
+use crate::resources::ResourceAlreadyExistsError;
+use crate::Context;
 
 #[cfg(test)]
 use mockall::automock;
 
 /// A result type for the plugin system.
-pub type PluginResult = Result<(), String>;
+pub type PluginResult = Result<(), PluginError>;
+
+/// Indicates why a [`Plugin`] failed to load.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PluginError {
+    /// A resource the plugin requires is missing.
+    MissingResource(TypeId),
+
+    /// An IO operation failed while loading the plugin.
+    IoError(std::io::Error),
+
+    /// The named plugin depends on a plugin which was never added.
+    MissingDependency {
+        /// The name of the plugin with the missing dependency.
+        plugin: String,
+
+        /// The [`TypeId`] of the plugin which was never added.
+        dependency: TypeId,
+    },
+
+    /// The named plugin is part of a dependency cycle.
+    DependencyCycle(String),
+
+    /// The named plugin was added more than once, under [`DuplicatePluginPolicy::Error`].
+    DuplicatePlugin(String),
+
+    /// A prebuilt [`Resources`](crate::resources::Resources) container was added to a builder
+    /// which already had resources.
+    ///
+    /// See [`EngineBuilder::with_resources()`](crate::engine_builder::EngineBuilder::with_resources()).
+    ResourceCollision(ResourceAlreadyExistsError),
+
+    /// Any other error, described by a message.
+    Other(String),
+}
+
+impl Display for PluginError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingResource(type_id) => write!(f, "Missing resource: {:?}", type_id),
+            Self::IoError(error) => write!(f, "IO error: {}", error),
+            Self::MissingDependency { plugin, dependency } => write!(
+                f,
+                "Plugin ({}) depends on a Plugin which was never added: {:?}",
+                plugin, dependency
+            ),
+            Self::DependencyCycle(plugin) => {
+                write!(f, "Plugin ({}) is part of a dependency cycle", plugin)
+            }
+            Self::DuplicatePlugin(plugin) => {
+                write!(f, "Plugin ({}) was added more than once", plugin)
+            }
+            Self::ResourceCollision(error) => write!(f, "Resources could not be added: {}", error),
+            Self::Other(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for PluginError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::IoError(error) => Some(error),
+            Self::ResourceCollision(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for PluginError {
+    fn from(error: std::io::Error) -> Self {
+        Self::IoError(error)
+    }
+}
+
+impl From<String> for PluginError {
+    fn from(message: String) -> Self {
+        Self::Other(message)
+    }
+}
+
+impl From<&str> for PluginError {
+    fn from(message: &str) -> Self {
+        Self::Other(message.to_string())
+    }
+}
 
 /// A module which adds new functionality to the engine.
 #[cfg_attr(test, automock)]
@@ -26,43 +116,313 @@ pub trait Plugin {
     /// the setup process, it's not possible to add additional plugins.  Nothing will happen if you
     /// try.
     fn load(&mut self, builder: &mut EngineBuilder<PluginLoad>) -> PluginResult;
+
+    /// Returns the types of any plugins which must be loaded before this one.
+    ///
+    /// Plugins are loaded in the order they were added, unless they declare dependencies, in which
+    /// case the dependencies are always loaded first.  Loading will fail if a dependency was never
+    /// added, or if the dependencies form a cycle.
+    fn dependencies(&self) -> Vec<TypeId> {
+        Vec::new()
+    }
+
+    /// Cleans up anything the plugin set up during [`Plugin::load()`].
+    ///
+    /// Loaded plugins are kept alive by the [`Context`], and torn down in reverse load order when
+    /// the [`Context`] is dropped, which normally happens once the main-loop has exited.
+    #[allow(unused)]
+    fn teardown(&mut self, context: &mut Context) {}
+}
+
+/// A [`Plugin`] which needs to do asynchronous work while loading.
+///
+/// Async plugins are added with [`EngineBuilder::with_async_plugin()`], and loaded in the same
+/// order as regular plugins.  The engine blocks on [`AsyncPlugin::load()`] until it completes,
+/// so plugins don't have to block on their own futures.  Aside from loading, async plugins work
+/// exactly like a regular [`Plugin`].
+#[allow(async_fn_in_trait)]
+pub trait AsyncPlugin {
+    /// Returns a people-friendly name for the plugin.
+    ///
+    /// See [`Plugin::name()`].
+    fn name(&self) -> &str;
+
+    /// Asynchronously loads the plugin using the provided [`EngineBuilder`].
+    ///
+    /// See [`Plugin::load()`].
+    async fn load(&mut self, builder: &mut EngineBuilder<PluginLoad>) -> PluginResult;
+
+    /// Returns the types of any plugins which must be loaded before this one.
+    ///
+    /// See [`Plugin::dependencies()`].
+    fn dependencies(&self) -> Vec<TypeId> {
+        Vec::new()
+    }
+
+    /// Cleans up anything the plugin set up during [`AsyncPlugin::load()`].
+    ///
+    /// See [`Plugin::teardown()`].
+    #[allow(unused)]
+    fn teardown(&mut self, context: &mut Context) {}
+}
+
+/// Adapts an [`AsyncPlugin`] to the [`Plugin`] interface, by blocking on its futures.
+struct AsyncPluginAdapter<T: AsyncPlugin>(T);
+
+impl<T: AsyncPlugin> Plugin for AsyncPluginAdapter<T> {
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn load(&mut self, builder: &mut EngineBuilder<PluginLoad>) -> PluginResult {
+        pollster::block_on(self.0.load(builder))
+    }
+
+    fn dependencies(&self) -> Vec<TypeId> {
+        self.0.dependencies()
+    }
+
+    fn teardown(&mut self, context: &mut Context) {
+        self.0.teardown(context)
+    }
+}
+
+/// Decides what happens when the same [`Plugin`] type is added more than once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicatePluginPolicy {
+    /// Only the first instance is loaded, and a warning is logged for the rest.
+    #[default]
+    Skip,
+
+    /// Loading fails with [`PluginError::DuplicatePlugin`].
+    Error,
+
+    /// Every instance is loaded.
+    Allow,
+}
+
+struct PluginEntry {
+    type_id: TypeId,
+    plugin: Box<dyn Plugin>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum VisitState {
+    Unvisited,
+    Visiting,
+    Visited,
 }
 
 #[derive(Default)]
 pub(crate) struct PluginLoader {
-    plugins: Vec<Box<dyn Plugin>>,
+    plugins: Vec<PluginEntry>,
+    duplicate_policy: DuplicatePluginPolicy,
 }
 
 impl PluginLoader {
     pub fn new() -> Self {
         Self {
             plugins: Vec::new(),
+            duplicate_policy: DuplicatePluginPolicy::default(),
         }
     }
 
-    pub fn add_plugin(&mut self, plugin: Box<dyn Plugin + 'static>) {
-        self.plugins.push(plugin);
+    pub fn set_duplicate_policy(&mut self, duplicate_policy: DuplicatePluginPolicy) {
+        self.duplicate_policy = duplicate_policy;
+    }
+
+    pub fn add_plugin<T: Plugin + 'static>(&mut self, plugin: T) {
+        self.plugins.push(PluginEntry {
+            type_id: TypeId::of::<T>(),
+            plugin: Box::from(plugin),
+        });
+    }
+
+    /// Adds an [`AsyncPlugin`], keeping its own type for dependency, and duplicate checks.
+    pub fn add_async_plugin<T: AsyncPlugin + 'static>(&mut self, plugin: T) {
+        self.plugins.push(PluginEntry {
+            type_id: TypeId::of::<T>(),
+            plugin: Box::from(AsyncPluginAdapter(plugin)),
+        });
     }
 
     pub fn load_plugins(&mut self, builder: &mut EngineBuilder<PluginLoad>) -> PluginResult {
-        for plugin in &mut self.plugins {
+        if let Some(error) = self.remove_duplicates().into_iter().next() {
+            log::error!("Error adding Plugins: {}", error);
+            return Err(error);
+        }
+        if let Err(error) = self.sort_plugins() {
+            log::error!("Error resolving Plugin load order: {}", error);
+            return Err(error);
+        }
+        for PluginEntry { plugin, .. } in &mut self.plugins {
             match plugin.load(builder) {
                 Ok(_) => (),
                 Err(error) => {
-                    let error_message =
-                        format!("Error loading Plugin ({}): {}", plugin.name(), error);
-                    log::error!("{}", error_message);
+                    log::error!("Error loading Plugin ({}): {}", plugin.name(), error);
                     return Err(error);
                 }
             }
         }
         Ok(())
     }
+
+    /// Loads every plugin, and collects all errors, instead of stopping at the first one.
+    ///
+    /// Unlike [`PluginLoader::load_plugins()`], the plugin list is left as it is, so the plugins
+    /// can be loaded again later.  Returns the indices of the plugins which loaded successfully,
+    /// in load order, so they can be [torn down](PluginLoader::teardown_plugins()) afterwards.
+    ///
+    /// Loading only stops early if the load order can't be resolved.
+    pub fn validate(
+        &mut self,
+        builder: &mut EngineBuilder<PluginLoad>,
+    ) -> (Vec<usize>, Vec<PluginError>) {
+        let (duplicates, mut errors) = self.find_duplicates();
+        let load_order = match self.resolve_load_order() {
+            Ok(load_order) => load_order,
+            Err(error) => {
+                errors.push(error);
+                return (Vec::new(), errors);
+            }
+        };
+        let mut loaded = Vec::with_capacity(load_order.len());
+        for index in load_order {
+            if duplicates.contains(&index) {
+                continue;
+            }
+            let plugin = &mut self.plugins[index].plugin;
+            match plugin.load(builder) {
+                Ok(_) => loaded.push(index),
+                Err(error) => {
+                    log::error!("Error loading Plugin ({}): {}", plugin.name(), error);
+                    errors.push(error);
+                }
+            }
+        }
+        (loaded, errors)
+    }
+
+    /// Tears down the plugins at the given indices, in reverse order.
+    pub fn teardown_plugins(&mut self, indices: &[usize], context: &mut Context) {
+        for &index in indices.iter().rev() {
+            self.plugins[index].plugin.teardown(context);
+        }
+    }
+
+    /// Consumes the loader, and returns the plugins in load order.
+    pub fn into_plugins(self) -> Vec<Box<dyn Plugin>> {
+        self.plugins
+            .into_iter()
+            .map(|PluginEntry { plugin, .. }| plugin)
+            .collect()
+    }
+
+    /// Applies the [`DuplicatePluginPolicy`] to plugins of the same type.
+    ///
+    /// Only the first instance of each type is kept, and under [`DuplicatePluginPolicy::Error`],
+    /// an error is returned for every duplicate.
+    fn remove_duplicates(&mut self) -> Vec<PluginError> {
+        let (duplicates, errors) = self.find_duplicates();
+        let mut index = 0;
+        self.plugins.retain(|_| {
+            let keep = !duplicates.contains(&index);
+            index += 1;
+            keep
+        });
+        errors
+    }
+
+    /// Returns the indices of every duplicate plugin which shouldn't be loaded, along with the
+    /// errors reported for them.
+    fn find_duplicates(&self) -> (Vec<usize>, Vec<PluginError>) {
+        let mut duplicates = Vec::new();
+        let mut errors = Vec::new();
+        if self.duplicate_policy == DuplicatePluginPolicy::Allow {
+            return (duplicates, errors);
+        }
+        let mut seen = Vec::new();
+        for (index, entry) in self.plugins.iter().enumerate() {
+            if !seen.contains(&entry.type_id) {
+                seen.push(entry.type_id);
+                continue;
+            }
+            duplicates.push(index);
+            if self.duplicate_policy == DuplicatePluginPolicy::Error {
+                errors.push(PluginError::DuplicatePlugin(
+                    entry.plugin.name().to_string(),
+                ));
+            } else {
+                log::warn!(
+                    "Plugin ({}) was added more than once, skipping the duplicate",
+                    entry.plugin.name()
+                );
+            }
+        }
+        (duplicates, errors)
+    }
+
+    /// Reorders the plugins so every plugin comes after its dependencies.
+    fn sort_plugins(&mut self) -> PluginResult {
+        let load_order = self.resolve_load_order()?;
+        let mut plugins: Vec<Option<PluginEntry>> = std::mem::take(&mut self.plugins)
+            .into_iter()
+            .map(Some)
+            .collect();
+        self.plugins = load_order
+            .into_iter()
+            .map(|index| plugins[index].take().unwrap())
+            .collect();
+        Ok(())
+    }
+
+    fn resolve_load_order(&self) -> Result<Vec<usize>, PluginError> {
+        let mut visit_states = vec![VisitState::Unvisited; self.plugins.len()];
+        let mut load_order = Vec::with_capacity(self.plugins.len());
+        for index in 0..self.plugins.len() {
+            self.visit(index, &mut visit_states, &mut load_order)?;
+        }
+        Ok(load_order)
+    }
+
+    fn visit(
+        &self,
+        index: usize,
+        visit_states: &mut [VisitState],
+        load_order: &mut Vec<usize>,
+    ) -> PluginResult {
+        let plugin = &self.plugins[index].plugin;
+        match visit_states[index] {
+            VisitState::Visited => return Ok(()),
+            VisitState::Visiting => {
+                return Err(PluginError::DependencyCycle(plugin.name().to_string()))
+            }
+            VisitState::Unvisited => (),
+        }
+        visit_states[index] = VisitState::Visiting;
+        for dependency in plugin.dependencies() {
+            let dependency_index = self
+                .plugins
+                .iter()
+                .position(|entry| entry.type_id == dependency)
+                .ok_or_else(|| PluginError::MissingDependency {
+                    plugin: plugin.name().to_string(),
+                    dependency,
+                })?;
+            self.visit(dependency_index, visit_states, load_order)?;
+        }
+        visit_states[index] = VisitState::Visited;
+        load_order.push(index);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod plugin_loader_tests {
+    use std::{cell::RefCell, rc::Rc};
+
     use super::*;
+    use crate::events::{EngineEvent, EventReceiver};
 
     pub struct TestResource;
 
@@ -80,7 +440,9 @@ mod plugin_loader_tests {
         fn load(&mut self, builder: &mut EngineBuilder<PluginLoad>) -> PluginResult {
             builder.with_resource(TestResource);
             if self.should_fail {
-                Err("Nah, I don't really feel like it.  Why don't you ask me later?".to_string())
+                Err(PluginError::Other(
+                    "Nah, I don't really feel like it.  Why don't you ask me later?".to_string(),
+                ))
             } else {
                 Ok(())
             }
@@ -108,4 +470,421 @@ mod plugin_loader_tests {
         let result = crate::init().with_plugin(TestPlugin::new(true)).build();
         assert!(result.is_err(), "The build should have failed");
     }
+
+    type LoadOrder = Rc<RefCell<Vec<usize>>>;
+
+    pub struct OrderedPlugin<const ID: usize> {
+        load_order: LoadOrder,
+        dependencies: Vec<TypeId>,
+    }
+
+    impl<const ID: usize> OrderedPlugin<ID> {
+        pub fn new(load_order: &LoadOrder, dependencies: Vec<TypeId>) -> Self {
+            Self {
+                load_order: load_order.clone(),
+                dependencies,
+            }
+        }
+    }
+
+    impl<const ID: usize> Plugin for OrderedPlugin<ID> {
+        fn load(&mut self, _builder: &mut EngineBuilder<PluginLoad>) -> PluginResult {
+            self.load_order.borrow_mut().push(ID);
+            Ok(())
+        }
+
+        fn name(&self) -> &str {
+            "Ordered Plugin"
+        }
+
+        fn dependencies(&self) -> Vec<TypeId> {
+            self.dependencies.clone()
+        }
+    }
+
+    #[test]
+    fn should_load_dependencies_first() {
+        let load_order = LoadOrder::default();
+        let _engine = crate::init()
+            .with_plugin(OrderedPlugin::<2>::new(
+                &load_order,
+                vec![TypeId::of::<OrderedPlugin<1>>()],
+            ))
+            .with_plugin(OrderedPlugin::<1>::new(&load_order, vec![]))
+            .build()
+            .unwrap();
+
+        assert_eq!(*load_order.borrow(), vec![1, 2]);
+    }
+
+    #[test]
+    fn should_report_dependency_cycles() {
+        let load_order = LoadOrder::default();
+        let result = crate::init()
+            .with_plugin(OrderedPlugin::<1>::new(
+                &load_order,
+                vec![TypeId::of::<OrderedPlugin<2>>()],
+            ))
+            .with_plugin(OrderedPlugin::<2>::new(
+                &load_order,
+                vec![TypeId::of::<OrderedPlugin<1>>()],
+            ))
+            .build();
+
+        assert!(
+            matches!(result, Err(PluginError::DependencyCycle(_))),
+            "The build should have failed with a dependency cycle"
+        );
+        assert!(
+            load_order.borrow().is_empty(),
+            "No plugins should have been loaded"
+        );
+    }
+
+    #[test]
+    fn should_report_missing_dependencies() {
+        let load_order = LoadOrder::default();
+        let result = crate::init()
+            .with_plugin(OrderedPlugin::<1>::new(
+                &load_order,
+                vec![TypeId::of::<OrderedPlugin<2>>()],
+            ))
+            .build();
+
+        match result {
+            Err(PluginError::MissingDependency { plugin, dependency }) => {
+                assert_eq!(
+                    plugin,
+                    OrderedPlugin::<1>::new(&load_order, Vec::new()).name()
+                );
+                assert_eq!(dependency, TypeId::of::<OrderedPlugin<2>>());
+            }
+            _ => panic!("The build should have failed with a missing dependency"),
+        }
+    }
+
+    #[test]
+    fn should_teardown_plugins_when_the_engine_is_dropped() {
+        let mut plugin = MockPlugin::new();
+        plugin.expect_load().once().returning(|_| Ok(()));
+        plugin.expect_dependencies().return_const(Vec::new());
+        plugin.expect_teardown().once().return_const(());
+        let (mut event_loop, context) = crate::init().with_plugin(plugin).build().unwrap();
+
+        while let Some(event) = event_loop.next_event() {
+            if let Some(EngineEvent::EventsCleared) = event.downcast_ref() {
+                context.quit();
+            }
+        }
+    }
+
+    pub struct TeardownPlugin<const ID: usize> {
+        teardown_order: LoadOrder,
+    }
+
+    impl<const ID: usize> Plugin for TeardownPlugin<ID> {
+        fn load(&mut self, _builder: &mut EngineBuilder<PluginLoad>) -> PluginResult {
+            Ok(())
+        }
+
+        fn name(&self) -> &str {
+            "Teardown Plugin"
+        }
+
+        fn teardown(&mut self, _context: &mut Context) {
+            self.teardown_order.borrow_mut().push(ID);
+        }
+    }
+
+    #[test]
+    fn should_teardown_plugins_in_reverse_load_order() {
+        let teardown_order = LoadOrder::default();
+        let engine = crate::init()
+            .with_plugin(TeardownPlugin::<1> {
+                teardown_order: teardown_order.clone(),
+            })
+            .with_plugin(TeardownPlugin::<2> {
+                teardown_order: teardown_order.clone(),
+            })
+            .build()
+            .unwrap();
+
+        drop(engine);
+
+        assert_eq!(*teardown_order.borrow(), vec![2, 1]);
+    }
+
+    pub struct FailingPlugin {
+        error: Option<PluginError>,
+    }
+
+    impl Plugin for FailingPlugin {
+        fn load(&mut self, _builder: &mut EngineBuilder<PluginLoad>) -> PluginResult {
+            Err(self.error.take().unwrap())
+        }
+
+        fn name(&self) -> &str {
+            "Failing Plugin"
+        }
+    }
+
+    #[test]
+    fn should_propagate_missing_resource_errors() {
+        let result = crate::init()
+            .with_plugin(FailingPlugin {
+                error: Some(PluginError::MissingResource(TypeId::of::<TestResource>())),
+            })
+            .build();
+
+        match result {
+            Err(PluginError::MissingResource(type_id)) => {
+                assert_eq!(type_id, TypeId::of::<TestResource>())
+            }
+            _ => panic!("The MissingResource error was not propagated"),
+        }
+    }
+
+    #[test]
+    fn should_propagate_io_errors() {
+        let result = crate::init()
+            .with_plugin(FailingPlugin {
+                error: Some(std::io::Error::from(std::io::ErrorKind::NotFound).into()),
+            })
+            .build();
+
+        match result {
+            Err(PluginError::IoError(error)) => {
+                assert_eq!(error.kind(), std::io::ErrorKind::NotFound)
+            }
+            _ => panic!("The IoError was not propagated"),
+        }
+    }
+
+    pub struct CountingPlugin {
+        loads: Rc<RefCell<usize>>,
+    }
+
+    impl Plugin for CountingPlugin {
+        fn load(&mut self, _builder: &mut EngineBuilder<PluginLoad>) -> PluginResult {
+            *self.loads.borrow_mut() += 1;
+            Ok(())
+        }
+
+        fn name(&self) -> &str {
+            "Counting Plugin"
+        }
+    }
+
+    fn build_with_duplicates(policy: DuplicatePluginPolicy) -> (usize, PluginResult) {
+        let loads = Rc::new(RefCell::new(0));
+        let result = crate::init()
+            .with_duplicate_plugin_policy(policy)
+            .with_plugin(CountingPlugin {
+                loads: loads.clone(),
+            })
+            .with_plugin(CountingPlugin {
+                loads: loads.clone(),
+            })
+            .build()
+            .map(|_| ());
+        let loads = *loads.borrow();
+        (loads, result)
+    }
+
+    #[test]
+    fn should_skip_duplicate_plugins_by_default() {
+        let loads = Rc::new(RefCell::new(0));
+        crate::init()
+            .with_plugin(CountingPlugin {
+                loads: loads.clone(),
+            })
+            .with_plugin(CountingPlugin {
+                loads: loads.clone(),
+            })
+            .build()
+            .unwrap();
+
+        assert_eq!(*loads.borrow(), 1);
+    }
+
+    #[test]
+    fn should_skip_duplicate_plugins_under_the_skip_policy() {
+        let (loads, result) = build_with_duplicates(DuplicatePluginPolicy::Skip);
+
+        assert_eq!(loads, 1);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_report_duplicate_plugins_under_the_error_policy() {
+        let (loads, result) = build_with_duplicates(DuplicatePluginPolicy::Error);
+
+        assert_eq!(loads, 0);
+        assert!(matches!(result, Err(PluginError::DuplicatePlugin(_))));
+    }
+
+    #[test]
+    fn should_load_duplicate_plugins_under_the_allow_policy() {
+        let (loads, result) = build_with_duplicates(DuplicatePluginPolicy::Allow);
+
+        assert_eq!(loads, 2);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_report_every_error_when_validating() {
+        let loads = Rc::new(RefCell::new(0));
+        let result = crate::init()
+            .with_plugin(TestPlugin::new(true))
+            .with_plugin(CountingPlugin {
+                loads: loads.clone(),
+            })
+            .with_plugin(FailingPlugin {
+                error: Some(std::io::Error::from(std::io::ErrorKind::NotFound).into()),
+            })
+            .validate();
+
+        let errors = result.expect_err("The validation should have failed");
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(errors[0], PluginError::Other(_)));
+        assert!(matches!(errors[1], PluginError::IoError(_)));
+        assert_eq!(*loads.borrow(), 1, "Validation should not stop early");
+    }
+
+    #[test]
+    fn should_report_duplicates_alongside_load_errors_when_validating() {
+        let errors = crate::init()
+            .with_duplicate_plugin_policy(DuplicatePluginPolicy::Error)
+            .with_plugin(TestPlugin::new(true))
+            .with_plugin(TestPlugin::new(true))
+            .validate()
+            .unwrap_err();
+
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(errors[0], PluginError::DuplicatePlugin(_)));
+        assert!(matches!(errors[1], PluginError::Other(_)));
+    }
+
+    #[test]
+    fn should_validate_plugins_which_load_cleanly() {
+        let result = crate::init().with_plugin(TestPlugin::new(false)).validate();
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_load_every_plugin_when_building_after_validating() {
+        let loads = Rc::new(RefCell::new(0));
+        let mut engine_builder = crate::init();
+        engine_builder
+            .with_resource(0)
+            .with_plugin(TestPlugin::new(false))
+            .with_plugin(CountingPlugin {
+                loads: loads.clone(),
+            })
+            .with_plugin(CountingPlugin {
+                loads: loads.clone(),
+            });
+
+        engine_builder.validate().unwrap();
+        let (_event_loop, context) = engine_builder.build().unwrap();
+
+        assert_eq!(*loads.borrow(), 2, "Each load should skip the duplicate");
+        assert!(context.resources().get::<TestResource>().is_ok());
+        assert!(context.resources().get::<i32>().is_ok());
+    }
+
+    #[test]
+    fn should_teardown_loaded_plugins_after_validating() {
+        let teardown_order = LoadOrder::default();
+        let result = crate::init()
+            .with_plugin(TeardownPlugin::<1> {
+                teardown_order: teardown_order.clone(),
+            })
+            .with_plugin(TestPlugin::new(true))
+            .with_plugin(TeardownPlugin::<2> {
+                teardown_order: teardown_order.clone(),
+            })
+            .validate();
+
+        assert!(result.is_err());
+        assert_eq!(*teardown_order.borrow(), vec![2, 1]);
+    }
+
+    /// A future which is pending the first time it's polled, to make sure it's actually awaited.
+    struct YieldOnce {
+        yielded: bool,
+    }
+
+    impl std::future::Future for YieldOnce {
+        type Output = ();
+
+        fn poll(
+            mut self: std::pin::Pin<&mut Self>,
+            context: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<()> {
+            if self.yielded {
+                std::task::Poll::Ready(())
+            } else {
+                self.yielded = true;
+                context.waker().wake_by_ref();
+                std::task::Poll::Pending
+            }
+        }
+    }
+
+    pub struct TestAsyncPlugin {
+        load_order: LoadOrder,
+        dependencies: Vec<TypeId>,
+    }
+
+    impl AsyncPlugin for TestAsyncPlugin {
+        fn name(&self) -> &str {
+            "Test Async Plugin"
+        }
+
+        async fn load(&mut self, builder: &mut EngineBuilder<PluginLoad>) -> PluginResult {
+            YieldOnce { yielded: false }.await;
+            builder.with_resource(TestResource);
+            self.load_order.borrow_mut().push(0);
+            Ok(())
+        }
+
+        fn dependencies(&self) -> Vec<TypeId> {
+            self.dependencies.clone()
+        }
+    }
+
+    #[test]
+    fn should_finish_loading_async_plugins_before_build_returns() {
+        let load_order = Rc::new(RefCell::new(Vec::new()));
+        let (_event_loop, context) = crate::init()
+            .with_async_plugin(TestAsyncPlugin {
+                load_order: load_order.clone(),
+                dependencies: Vec::new(),
+            })
+            .build()
+            .unwrap();
+
+        assert!(context.resources().get::<TestResource>().is_ok());
+        assert_eq!(*load_order.borrow(), vec![0]);
+    }
+
+    #[test]
+    fn should_resolve_dependencies_on_async_plugins() {
+        let load_order = Rc::new(RefCell::new(Vec::new()));
+        crate::init()
+            .with_plugin(OrderedPlugin::<1>::new(
+                &load_order,
+                vec![TypeId::of::<TestAsyncPlugin>()],
+            ))
+            .with_async_plugin(TestAsyncPlugin {
+                load_order: load_order.clone(),
+                dependencies: Vec::new(),
+            })
+            .build()
+            .unwrap();
+
+        assert_eq!(*load_order.borrow(), vec![0, 1]);
+    }
 }
@@ -0,0 +1,65 @@
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
+
+/// Tracks whether the engine has been asked to quit, and the exit code it should quit with.
+///
+/// The [`Context`](crate::Context) shares its quit state through an [`Arc`](std::sync::Arc).  A
+/// handle to it can be taken with [`Context::quit_state()`](crate::Context::quit_state()), and
+/// read after the [`Context`](crate::Context) is gone, such as once the main-loop has exited and
+/// consumed the engine.
+#[derive(Debug, Default)]
+pub struct QuitState {
+    has_quit: AtomicBool,
+    exit_code: AtomicI32,
+}
+
+impl QuitState {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the engine has been asked to quit.
+    pub fn has_quit(&self) -> bool {
+        self.has_quit.load(Ordering::Relaxed)
+    }
+
+    /// Returns the exit code recorded by the last call to
+    /// [`Context::quit_with()`](crate::Context::quit_with()).
+    ///
+    /// Returns `0` if no exit code was recorded.
+    pub fn exit_code(&self) -> i32 {
+        self.exit_code.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set_has_quit(&self) {
+        self.has_quit.store(true, Ordering::Relaxed);
+    }
+
+    pub(crate) fn set_exit_code(&self, code: i32) {
+        self.exit_code.store(code, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod quit_state_tests {
+    use super::*;
+
+    #[test]
+    fn should_not_have_quit_by_default() {
+        let quit_state = QuitState::new();
+
+        assert!(!quit_state.has_quit());
+        assert_eq!(quit_state.exit_code(), 0);
+    }
+
+    #[test]
+    fn should_outlive_the_context() {
+        let (_event_loop, context) = crate::init().build().unwrap();
+        let quit_state = context.quit_state();
+
+        context.quit_with(3);
+        drop(context);
+
+        assert!(quit_state.has_quit());
+        assert_eq!(quit_state.exit_code(), 3);
+    }
+}
@@ -0,0 +1,81 @@
+//! Provides a shared resource container which is thread-safe, and lock-free
+//!
+//! Wolf Engine re-exports [`shared_resources`], see the original crate for details.
+
+use std::fmt::{Display, Formatter};
+use std::ops::DerefMut;
+
+pub use shared_resources::*;
+
+/// Indicates a resource could not be added, because a resource of the same type already exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceAlreadyExistsError;
+
+impl Display for ResourceAlreadyExistsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "A resource of the same type already exists")
+    }
+}
+
+impl std::error::Error for ResourceAlreadyExistsError {}
+
+/// Provides extra methods for [`Resources`].
+pub trait ResourcesExt {
+    /// Returns the resource of type `T`, inserting its [default](Default) value first if it's
+    /// missing.
+    fn get_or_insert_default<T: Resource + Default>(&mut self) -> impl DerefMut<Target = T> + '_ {
+        self.get_or_insert_with(T::default)
+    }
+
+    /// Returns the resource of type `T`, inserting the value returned by `f` first if it's
+    /// missing.
+    fn get_or_insert_with<T, F>(&mut self, f: F) -> impl DerefMut<Target = T> + '_
+    where
+        T: Resource,
+        F: FnOnce() -> T;
+}
+
+impl ResourcesExt for Resources {
+    fn get_or_insert_with<T, F>(&mut self, f: F) -> impl DerefMut<Target = T> + '_
+    where
+        T: Resource,
+        F: FnOnce() -> T,
+    {
+        if self.get::<T>().is_err() {
+            self.insert(f());
+        }
+        self.get_mut::<T>()
+            .expect("The resource should have just been inserted")
+    }
+}
+
+#[cfg(test)]
+mod resources_ext_tests {
+    use super::*;
+
+    #[test]
+    fn should_insert_the_default_when_missing() {
+        let mut resources = Resources::default();
+
+        assert_eq!(*resources.get_or_insert_default::<i32>(), 0);
+        assert_eq!(*resources.get::<i32>().unwrap(), 0);
+    }
+
+    #[test]
+    fn should_return_the_existing_resource_when_present() {
+        let mut resources = Resources::default();
+        resources.insert(42);
+
+        assert_eq!(*resources.get_or_insert_default::<i32>(), 42);
+        assert_eq!(*resources.get_or_insert_with(|| 7), 42);
+    }
+
+    #[test]
+    fn should_return_a_mutable_reference() {
+        let mut resources = Resources::default();
+
+        *resources.get_or_insert_with(|| String::from("Hello")) += ", World!";
+
+        assert_eq!(*resources.get::<String>().unwrap(), "Hello, World!");
+    }
+}
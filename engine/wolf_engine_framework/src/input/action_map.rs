@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::input::{GamepadButton, InputState, Key, MouseButton};
+
+/// Identifies a physical input which can trigger an action in the [`ActionMap`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Binding {
+    /// A key on the keyboard.
+    Key(Key),
+
+    /// A button on the mouse.
+    MouseButton(MouseButton),
+
+    /// A button on any connected gamepad.
+    GamepadButton(GamepadButton),
+}
+
+impl From<Key> for Binding {
+    fn from(key: Key) -> Self {
+        Self::Key(key)
+    }
+}
+
+impl From<MouseButton> for Binding {
+    fn from(button: MouseButton) -> Self {
+        Self::MouseButton(button)
+    }
+}
+
+impl From<GamepadButton> for Binding {
+    fn from(button: GamepadButton) -> Self {
+        Self::GamepadButton(button)
+    }
+}
+
+/// Maps named actions, such as `"jump"`, or `"fire"` to the physical inputs which trigger them.
+///
+/// Game code asks about actions, instead of physical inputs, which makes it easy to let players
+/// rebind their controls.  Actions are queried against the current [`InputState`].
+///
+/// With the `serde` feature enabled, the map can be loaded from a config file, where each action
+/// is a list of bindings.
+///
+/// # Examples
+///
+/// ```
+/// # use wolf_engine_framework::input::*;
+/// #
+/// let mut action_map = ActionMap::new();
+/// action_map.bind("jump", Key::Space);
+/// action_map.bind("jump", GamepadButton::South);
+///
+/// let mut input_state = InputState::new();
+/// input_state.handle_event(&InputEvent::KeyDown(Key::Space));
+///
+/// assert!(action_map.action_just_pressed(&input_state, "jump"));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+pub struct ActionMap {
+    actions: HashMap<String, Vec<Binding>>,
+}
+
+impl ActionMap {
+    /// Creates a new, empty `ActionMap`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Binds a physical input to the named action.
+    ///
+    /// An action can have any number of bindings, and is triggered by any of them.
+    pub fn bind<B: Into<Binding>>(&mut self, action: &str, binding: B) {
+        let binding = binding.into();
+        let bindings = self.actions.entry(action.to_string()).or_default();
+        if !bindings.contains(&binding) {
+            bindings.push(binding);
+        }
+    }
+
+    /// Removes all bindings from the named action.
+    pub fn unbind_all(&mut self, action: &str) {
+        self.actions.remove(action);
+    }
+
+    /// Returns the bindings for the named action.
+    pub fn bindings(&self, action: &str) -> &[Binding] {
+        self.actions.get(action).map_or(&[], |bindings| bindings)
+    }
+
+    /// Returns `true` if any of the action's bindings are currently held down.
+    pub fn action_active(&self, input_state: &InputState, action: &str) -> bool {
+        self.bindings(action).iter().any(|binding| match *binding {
+            Binding::Key(key) => input_state.is_key_down(key),
+            Binding::MouseButton(button) => input_state.is_mouse_button_down(button),
+            Binding::GamepadButton(button) => input_state.any_gamepad_button_down(button),
+        })
+    }
+
+    /// Returns `true` if any of the action's bindings were pressed this frame.
+    pub fn action_just_pressed(&self, input_state: &InputState, action: &str) -> bool {
+        self.bindings(action).iter().any(|binding| match *binding {
+            Binding::Key(key) => input_state.just_pressed(key),
+            Binding::MouseButton(button) => input_state.mouse_button_just_pressed(button),
+            Binding::GamepadButton(button) => input_state.any_gamepad_just_pressed(button),
+        })
+    }
+}
+
+#[cfg(test)]
+mod action_map_tests {
+    use super::*;
+    use crate::input::{GamepadEvent, GamepadId, InputEvent};
+
+    #[test]
+    fn should_trigger_actions_on_the_frame_they_are_pressed() {
+        let mut action_map = ActionMap::new();
+        action_map.bind("jump", Key::Space);
+        let mut input_state = InputState::new();
+
+        input_state.handle_event(&InputEvent::KeyDown(Key::Space));
+        assert!(action_map.action_just_pressed(&input_state, "jump"));
+        assert!(action_map.action_active(&input_state, "jump"));
+
+        input_state.start_frame();
+        assert!(!action_map.action_just_pressed(&input_state, "jump"));
+        assert!(action_map.action_active(&input_state, "jump"));
+        assert!(!action_map.action_active(&input_state, "fire"));
+    }
+
+    #[test]
+    fn should_trigger_actions_from_any_binding() {
+        let mut action_map = ActionMap::new();
+        action_map.bind("fire", MouseButton::Left);
+        action_map.bind("fire", GamepadButton::RightBumper);
+        let mut input_state = InputState::new();
+        input_state.handle_event(&InputEvent::Gamepad(GamepadEvent::Connected(GamepadId(1))));
+
+        input_state.handle_event(&InputEvent::Gamepad(GamepadEvent::ButtonDown(
+            GamepadId(1),
+            GamepadButton::RightBumper,
+        )));
+
+        assert!(action_map.action_just_pressed(&input_state, "fire"));
+    }
+
+    #[test]
+    fn should_unbind_actions() {
+        let mut action_map = ActionMap::new();
+        action_map.bind("jump", Key::Space);
+        action_map.bind("jump", Key::Space);
+        assert_eq!(action_map.bindings("jump"), [Binding::Key(Key::Space)]);
+
+        action_map.unbind_all("jump");
+
+        assert!(action_map.bindings("jump").is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_load_from_a_config_file() {
+        let toml_str = r#"
+        jump = [{ Key = "Space" }, { GamepadButton = "South" }]
+        fire = [{ MouseButton = "Left" }]
+        "#;
+
+        let action_map: ActionMap = toml::from_str(toml_str).unwrap();
+
+        assert_eq!(
+            action_map.bindings("jump"),
+            [
+                Binding::Key(Key::Space),
+                Binding::GamepadButton(GamepadButton::South)
+            ]
+        );
+        assert_eq!(
+            action_map.bindings("fire"),
+            [Binding::MouseButton(MouseButton::Left)]
+        );
+    }
+}
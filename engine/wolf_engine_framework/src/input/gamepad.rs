@@ -0,0 +1,73 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Identifies a connected gamepad.
+///
+/// Ids are assigned by the input back-end, and stay the same for as long as the gamepad is
+/// connected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GamepadId(pub u32);
+
+/// Identifies an analog axis on a gamepad.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GamepadAxis {
+    LeftStickX,
+    LeftStickY,
+    RightStickX,
+    RightStickY,
+    LeftTrigger,
+    RightTrigger,
+}
+
+/// Identifies a button on a gamepad.
+///
+/// The face buttons are named by their position, rather than their label, since labels differ
+/// between controllers.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GamepadButton {
+    South,
+    East,
+    West,
+    North,
+    LeftBumper,
+    RightBumper,
+    LeftStick,
+    RightStick,
+    Select,
+    Start,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+
+    /// A button without a named variant, identified by its index.
+    Other(u16),
+}
+
+/// Provides the events emitted by gamepads.
+///
+/// Input back-ends send these wrapped in [`InputEvent::Gamepad`](crate::input::InputEvent).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GamepadEvent {
+    /// Emitted when a gamepad is connected.
+    Connected(GamepadId),
+
+    /// Emitted when a gamepad is disconnected.
+    Disconnected(GamepadId),
+
+    /// Emitted when a gamepad button is pressed.
+    ButtonDown(GamepadId, GamepadButton),
+
+    /// Emitted when a gamepad button is released.
+    ButtonUp(GamepadId, GamepadButton),
+
+    /// Emitted when an analog axis moves, with its new value.
+    ///
+    /// Stick axes range from `-1.0` to `1.0`, and triggers range from `0.0` to `1.0`.
+    AxisMoved(GamepadId, GamepadAxis, f32),
+}
@@ -0,0 +1,32 @@
+use wolf_engine_core::events::Event;
+
+use crate::input::{GamepadEvent, Key, MouseButton};
+
+/// Provides the keyboard, mouse, and gamepad events handled by the framework.
+///
+/// Input back-ends send these events through the engine's
+/// [`MainEventSender`](wolf_engine_core::events::MainEventSender).  The
+/// [`EngineDriver`](crate::main_loop::EngineDriver) forwards them to the active
+/// [`Scene`](crate::scenes::Scene) on the [`Stage`](crate::scenes::Stage).
+#[non_exhaustive]
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub enum InputEvent {
+    /// Emitted when a key is pressed.
+    KeyDown(Key),
+
+    /// Emitted when a key is released.
+    KeyUp(Key),
+
+    /// Emitted when a mouse button is pressed.
+    MouseButtonDown(MouseButton),
+
+    /// Emitted when a mouse button is released.
+    MouseButtonUp(MouseButton),
+
+    /// Emitted when the mouse moves, with its new position, in pixels, relative to the top-left
+    /// corner of the window.
+    MouseMoved { x: f32, y: f32 },
+
+    /// Emitted when something happens on a gamepad.
+    Gamepad(GamepadEvent),
+}
@@ -0,0 +1,387 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::input::{
+    GamepadAxis, GamepadButton, GamepadEvent, GamepadId, InputEvent, Key, MouseButton,
+};
+
+/// The default [dead-zone](InputState::set_dead_zone()) for analog gamepad axes.
+pub const DEFAULT_DEAD_ZONE: f32 = 0.1;
+
+/// Provides a polling interface over keyboard, mouse, and gamepad input.
+///
+/// Instead of reacting to [`InputEvents`](InputEvent) as they happen, game code can ask whether a
+/// key is held down, or whether it was pressed / released this frame.
+///
+/// The [`EngineDriver`](crate::main_loop::EngineDriver) inserts an `InputState` resource, updates
+/// it from incoming [`InputEvents`](InputEvent), and clears the per-frame edges at the start of
+/// every frame.
+///
+/// # Examples
+///
+/// ```
+/// # use wolf_engine_framework::input::*;
+/// #
+/// let mut input_state = InputState::new();
+///
+/// input_state.handle_event(&InputEvent::KeyDown(Key::W));
+/// assert!(input_state.is_key_down(Key::W));
+/// assert!(input_state.just_pressed(Key::W));
+///
+/// input_state.start_frame();
+/// assert!(input_state.is_key_down(Key::W));
+/// assert!(!input_state.just_pressed(Key::W));
+/// ```
+#[derive(Debug)]
+pub struct InputState {
+    keys_down: HashSet<Key>,
+    keys_pressed: HashSet<Key>,
+    keys_released: HashSet<Key>,
+    mouse_buttons_down: HashSet<MouseButton>,
+    mouse_buttons_pressed: HashSet<MouseButton>,
+    mouse_buttons_released: HashSet<MouseButton>,
+    mouse_position: (f32, f32),
+    gamepads: HashMap<GamepadId, GamepadState>,
+    dead_zone: f32,
+}
+
+#[derive(Debug, Default)]
+struct GamepadState {
+    buttons_down: HashSet<GamepadButton>,
+    buttons_pressed: HashSet<GamepadButton>,
+    buttons_released: HashSet<GamepadButton>,
+    axes: HashMap<GamepadAxis, f32>,
+}
+
+impl Default for InputState {
+    fn default() -> Self {
+        Self {
+            keys_down: HashSet::new(),
+            keys_pressed: HashSet::new(),
+            keys_released: HashSet::new(),
+            mouse_buttons_down: HashSet::new(),
+            mouse_buttons_pressed: HashSet::new(),
+            mouse_buttons_released: HashSet::new(),
+            mouse_position: (0.0, 0.0),
+            gamepads: HashMap::new(),
+            dead_zone: DEFAULT_DEAD_ZONE,
+        }
+    }
+}
+
+impl InputState {
+    /// Creates a new `InputState`, with nothing held down.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the dead-zone for analog gamepad axes.
+    ///
+    /// Axis values with a magnitude below the dead-zone are reported as `0.0`, which hides the
+    /// small amount of drift most analog sticks have at rest.  Defaults to [`DEFAULT_DEAD_ZONE`].
+    pub fn set_dead_zone(&mut self, dead_zone: f32) {
+        self.dead_zone = dead_zone;
+    }
+
+    /// Returns the dead-zone for analog gamepad axes.
+    pub fn dead_zone(&self) -> f32 {
+        self.dead_zone
+    }
+
+    /// Updates the state from the provided [`InputEvent`].
+    pub fn handle_event(&mut self, event: &InputEvent) {
+        match *event {
+            InputEvent::KeyDown(key) => {
+                if self.keys_down.insert(key) {
+                    self.keys_pressed.insert(key);
+                }
+            }
+            InputEvent::KeyUp(key) => {
+                if self.keys_down.remove(&key) {
+                    self.keys_released.insert(key);
+                }
+            }
+            InputEvent::MouseButtonDown(button) => {
+                if self.mouse_buttons_down.insert(button) {
+                    self.mouse_buttons_pressed.insert(button);
+                }
+            }
+            InputEvent::MouseButtonUp(button) => {
+                if self.mouse_buttons_down.remove(&button) {
+                    self.mouse_buttons_released.insert(button);
+                }
+            }
+            InputEvent::MouseMoved { x, y } => self.mouse_position = (x, y),
+            InputEvent::Gamepad(event) => self.handle_gamepad_event(event),
+        }
+    }
+
+    /// Events for gamepads which aren't connected are ignored, so late events can't bring back a
+    /// disconnected gamepad.
+    fn handle_gamepad_event(&mut self, event: GamepadEvent) {
+        match event {
+            GamepadEvent::Connected(id) => {
+                self.gamepads.entry(id).or_default();
+            }
+            GamepadEvent::Disconnected(id) => {
+                self.gamepads.remove(&id);
+            }
+            GamepadEvent::ButtonDown(id, button) => {
+                if let Some(gamepad) = self.gamepads.get_mut(&id) {
+                    if gamepad.buttons_down.insert(button) {
+                        gamepad.buttons_pressed.insert(button);
+                    }
+                }
+            }
+            GamepadEvent::ButtonUp(id, button) => {
+                if let Some(gamepad) = self.gamepads.get_mut(&id) {
+                    if gamepad.buttons_down.remove(&button) {
+                        gamepad.buttons_released.insert(button);
+                    }
+                }
+            }
+            GamepadEvent::AxisMoved(id, axis, value) => {
+                if let Some(gamepad) = self.gamepads.get_mut(&id) {
+                    gamepad.axes.insert(axis, value);
+                }
+            }
+        }
+    }
+
+    /// Clears the keys, and buttons pressed, and released during the previous frame.
+    pub fn start_frame(&mut self) {
+        self.keys_pressed.clear();
+        self.keys_released.clear();
+        self.mouse_buttons_pressed.clear();
+        self.mouse_buttons_released.clear();
+        for gamepad in self.gamepads.values_mut() {
+            gamepad.buttons_pressed.clear();
+            gamepad.buttons_released.clear();
+        }
+    }
+
+    /// Returns `true` if the key is currently held down.
+    pub fn is_key_down(&self, key: Key) -> bool {
+        self.keys_down.contains(&key)
+    }
+
+    /// Returns `true` if the key was pressed this frame.
+    pub fn just_pressed(&self, key: Key) -> bool {
+        self.keys_pressed.contains(&key)
+    }
+
+    /// Returns `true` if the key was released this frame.
+    pub fn just_released(&self, key: Key) -> bool {
+        self.keys_released.contains(&key)
+    }
+
+    /// Returns `true` if the mouse button is currently held down.
+    pub fn is_mouse_button_down(&self, button: MouseButton) -> bool {
+        self.mouse_buttons_down.contains(&button)
+    }
+
+    /// Returns `true` if the mouse button was pressed this frame.
+    pub fn mouse_button_just_pressed(&self, button: MouseButton) -> bool {
+        self.mouse_buttons_pressed.contains(&button)
+    }
+
+    /// Returns `true` if the mouse button was released this frame.
+    pub fn mouse_button_just_released(&self, button: MouseButton) -> bool {
+        self.mouse_buttons_released.contains(&button)
+    }
+
+    /// Returns the last known mouse position, in pixels, relative to the top-left corner of the
+    /// window.
+    pub fn mouse_position(&self) -> (f32, f32) {
+        self.mouse_position
+    }
+
+    /// Returns `true` if the gamepad is connected.
+    pub fn is_gamepad_connected(&self, id: GamepadId) -> bool {
+        self.gamepads.contains_key(&id)
+    }
+
+    /// Returns the value of a gamepad's analog axis, with the dead-zone applied.
+    ///
+    /// Returns `0.0` if the gamepad isn't connected, or the axis hasn't moved yet.
+    pub fn gamepad_axis(&self, id: GamepadId, axis: GamepadAxis) -> f32 {
+        let value = self
+            .gamepads
+            .get(&id)
+            .and_then(|gamepad| gamepad.axes.get(&axis))
+            .copied()
+            .unwrap_or(0.0);
+        if value.abs() < self.dead_zone {
+            0.0
+        } else {
+            value
+        }
+    }
+
+    /// Returns `true` if the gamepad button is currently held down.
+    pub fn gamepad_button_down(&self, id: GamepadId, button: GamepadButton) -> bool {
+        self.gamepads
+            .get(&id)
+            .is_some_and(|gamepad| gamepad.buttons_down.contains(&button))
+    }
+
+    /// Returns `true` if the gamepad button was pressed this frame.
+    pub fn gamepad_just_pressed(&self, id: GamepadId, button: GamepadButton) -> bool {
+        self.gamepads
+            .get(&id)
+            .is_some_and(|gamepad| gamepad.buttons_pressed.contains(&button))
+    }
+
+    /// Returns `true` if the button is held down on any connected gamepad.
+    pub fn any_gamepad_button_down(&self, button: GamepadButton) -> bool {
+        self.gamepads
+            .values()
+            .any(|gamepad| gamepad.buttons_down.contains(&button))
+    }
+
+    /// Returns `true` if the button was pressed this frame on any connected gamepad.
+    pub fn any_gamepad_just_pressed(&self, button: GamepadButton) -> bool {
+        self.gamepads
+            .values()
+            .any(|gamepad| gamepad.buttons_pressed.contains(&button))
+    }
+
+    /// Returns `true` if the gamepad button was released this frame.
+    pub fn gamepad_just_released(&self, id: GamepadId, button: GamepadButton) -> bool {
+        self.gamepads
+            .get(&id)
+            .is_some_and(|gamepad| gamepad.buttons_released.contains(&button))
+    }
+}
+
+#[cfg(test)]
+mod input_state_tests {
+    use super::*;
+
+    #[test]
+    fn should_track_key_edges_across_frames() {
+        let mut input_state = InputState::new();
+
+        input_state.start_frame();
+        input_state.handle_event(&InputEvent::KeyDown(Key::Space));
+        assert!(input_state.is_key_down(Key::Space));
+        assert!(input_state.just_pressed(Key::Space));
+        assert!(!input_state.just_released(Key::Space));
+
+        input_state.start_frame();
+        input_state.handle_event(&InputEvent::KeyDown(Key::Space));
+        assert!(input_state.is_key_down(Key::Space));
+        assert!(
+            !input_state.just_pressed(Key::Space),
+            "Key repeats should not count as new presses"
+        );
+
+        input_state.start_frame();
+        input_state.handle_event(&InputEvent::KeyUp(Key::Space));
+        assert!(!input_state.is_key_down(Key::Space));
+        assert!(input_state.just_released(Key::Space));
+
+        input_state.start_frame();
+        assert!(!input_state.is_key_down(Key::Space));
+        assert!(!input_state.just_released(Key::Space));
+    }
+
+    #[test]
+    fn should_track_the_mouse() {
+        let mut input_state = InputState::new();
+
+        input_state.handle_event(&InputEvent::MouseMoved { x: 10.0, y: 20.0 });
+        input_state.handle_event(&InputEvent::MouseButtonDown(MouseButton::Left));
+
+        assert_eq!(input_state.mouse_position(), (10.0, 20.0));
+        assert!(input_state.is_mouse_button_down(MouseButton::Left));
+        assert!(input_state.mouse_button_just_pressed(MouseButton::Left));
+        assert!(!input_state.is_mouse_button_down(MouseButton::Right));
+    }
+
+    const GAMEPAD: GamepadId = GamepadId(0);
+
+    fn gamepad_event(event: GamepadEvent) -> InputEvent {
+        InputEvent::Gamepad(event)
+    }
+
+    #[test]
+    fn should_apply_the_dead_zone_to_gamepad_axes() {
+        let mut input_state = InputState::new();
+        input_state.set_dead_zone(0.2);
+        input_state.handle_event(&gamepad_event(GamepadEvent::Connected(GAMEPAD)));
+
+        input_state.handle_event(&gamepad_event(GamepadEvent::AxisMoved(
+            GAMEPAD,
+            GamepadAxis::LeftStickX,
+            0.15,
+        )));
+        assert_eq!(
+            input_state.gamepad_axis(GAMEPAD, GamepadAxis::LeftStickX),
+            0.0
+        );
+
+        input_state.handle_event(&gamepad_event(GamepadEvent::AxisMoved(
+            GAMEPAD,
+            GamepadAxis::LeftStickX,
+            -0.5,
+        )));
+        assert_eq!(
+            input_state.gamepad_axis(GAMEPAD, GamepadAxis::LeftStickX),
+            -0.5
+        );
+    }
+
+    #[test]
+    fn should_track_gamepad_button_edges_across_frames() {
+        let mut input_state = InputState::new();
+        input_state.handle_event(&gamepad_event(GamepadEvent::Connected(GAMEPAD)));
+        assert!(input_state.is_gamepad_connected(GAMEPAD));
+
+        input_state.start_frame();
+        input_state.handle_event(&gamepad_event(GamepadEvent::ButtonDown(
+            GAMEPAD,
+            GamepadButton::South,
+        )));
+        assert!(input_state.gamepad_button_down(GAMEPAD, GamepadButton::South));
+        assert!(input_state.gamepad_just_pressed(GAMEPAD, GamepadButton::South));
+
+        input_state.start_frame();
+        assert!(input_state.gamepad_button_down(GAMEPAD, GamepadButton::South));
+        assert!(!input_state.gamepad_just_pressed(GAMEPAD, GamepadButton::South));
+
+        input_state.start_frame();
+        input_state.handle_event(&gamepad_event(GamepadEvent::ButtonUp(
+            GAMEPAD,
+            GamepadButton::South,
+        )));
+        assert!(!input_state.gamepad_button_down(GAMEPAD, GamepadButton::South));
+        assert!(input_state.gamepad_just_released(GAMEPAD, GamepadButton::South));
+
+        input_state.handle_event(&gamepad_event(GamepadEvent::Disconnected(GAMEPAD)));
+        assert!(!input_state.is_gamepad_connected(GAMEPAD));
+    }
+
+    #[test]
+    fn should_ignore_late_events_from_disconnected_gamepads() {
+        let mut input_state = InputState::new();
+        input_state.handle_event(&gamepad_event(GamepadEvent::Connected(GAMEPAD)));
+        input_state.handle_event(&gamepad_event(GamepadEvent::Disconnected(GAMEPAD)));
+
+        input_state.handle_event(&gamepad_event(GamepadEvent::AxisMoved(
+            GAMEPAD,
+            GamepadAxis::LeftStickX,
+            0.5,
+        )));
+        input_state.handle_event(&gamepad_event(GamepadEvent::ButtonDown(
+            GAMEPAD,
+            GamepadButton::South,
+        )));
+
+        assert!(!input_state.is_gamepad_connected(GAMEPAD));
+        assert!(!input_state.gamepad_button_down(GAMEPAD, GamepadButton::South));
+        assert_eq!(
+            input_state.gamepad_axis(GAMEPAD, GamepadAxis::LeftStickX),
+            0.0
+        );
+    }
+}
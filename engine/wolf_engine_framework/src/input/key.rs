@@ -0,0 +1,79 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Identifies a key on the keyboard.
+///
+/// Input back-ends are responsible for translating their native key codes into these.  Keys
+/// without a named variant are reported as [`Key::Other`], using the back-end's scan code.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Key {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    J,
+    K,
+    L,
+    M,
+    N,
+    O,
+    P,
+    Q,
+    R,
+    S,
+    T,
+    U,
+    V,
+    W,
+    X,
+    Y,
+    Z,
+    Key0,
+    Key1,
+    Key2,
+    Key3,
+    Key4,
+    Key5,
+    Key6,
+    Key7,
+    Key8,
+    Key9,
+    Up,
+    Down,
+    Left,
+    Right,
+    Space,
+    Enter,
+    Escape,
+    Tab,
+    Backspace,
+    LeftShift,
+    RightShift,
+    LeftControl,
+    RightControl,
+    LeftAlt,
+    RightAlt,
+
+    /// A key without a named variant, identified by its scan code.
+    Other(u32),
+}
+
+/// Identifies a button on the mouse.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+
+    /// A button without a named variant, identified by its index.
+    Other(u16),
+}
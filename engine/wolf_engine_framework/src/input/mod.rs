@@ -0,0 +1,12 @@
+//! Provides input types for the framework.
+
+mod action_map;
+pub use action_map::*;
+mod gamepad;
+pub use gamepad::*;
+mod input_event;
+pub use input_event::*;
+mod input_state;
+pub use input_state::*;
+mod key;
+pub use key::*;
@@ -5,38 +5,90 @@
 //! more on building your game.  The framework includes a plugin system, and game state /
 //! state-stack architecture.
 
+pub mod input;
 pub mod main_loop;
+pub mod rng;
 pub mod scenes;
+pub mod systems;
 
-use main_loop::{MainLoop, MainLoopResource};
+use main_loop::{
+    EngineDriver, FrameStatsEnabled, InitialSceneResource, MainLoop, MainLoopResource,
+};
+use scenes::{state::Unloaded, IntoScene, Scene, SceneTrait};
 use wolf_engine_core::{engine_builder::EngineBuilder, Engine};
 
 pub trait FrameworkBuilder {
     fn with_main_loop<T: MainLoop + 'static>(&mut self, main_loop: T) -> &mut Self;
+
+    /// Sets the [`Scene`] the default main-loop starts with.
+    fn with_initial_scene(&mut self, scene: Scene<Unloaded>) -> &mut Self;
+
+    /// Enables [`FrameStats`](main_loop::FrameStats) events, which are sent once per rendered
+    /// frame.
+    ///
+    /// Frame stats are off by default, to avoid the overhead when nobody is using them.
+    fn with_frame_stats(&mut self) -> &mut Self;
+
+    /// Sets the [`SceneTrait`] object the default main-loop starts with.
+    ///
+    /// This is a shortcut for wrapping the object in a [`Scene`], and calling
+    /// [`FrameworkBuilder::with_initial_scene()`].
+    fn with_scene<S: SceneTrait + 'static>(&mut self, scene: S) -> &mut Self {
+        self.with_initial_scene(scene.into_scene())
+    }
 }
 
 impl<State> FrameworkBuilder for EngineBuilder<State> {
     fn with_main_loop<T: MainLoop + 'static>(&mut self, main_loop: T) -> &mut Self {
         self.with_resource(MainLoopResource::new(main_loop))
     }
+
+    fn with_initial_scene(&mut self, scene: Scene<Unloaded>) -> &mut Self {
+        self.with_resource(InitialSceneResource::new(scene))
+    }
+
+    fn with_frame_stats(&mut self) -> &mut Self {
+        self.with_resource(FrameStatsEnabled)
+    }
 }
 
 /// Runs the [`Engine`].
-pub fn run(engine: Engine) {
+///
+/// If no [`MainLoop`] was provided, the default main-loop is used.  The default main-loop pushes
+/// the initial [`Scene`](scenes::Scene) onto a [`Stage`](scenes::Stage), then updates, and renders
+/// the stage at the end of every frame.  The engine quits once the stage is empty.  If no
+/// initial [`Scene`] was provided, a warning is logged, and the engine quits right away.
+///
+/// Once the main-loop has exited, the exit code recorded with
+/// [`Context::quit_with()`](wolf_engine_core::Context::quit_with()) is returned, or `0` if there
+/// wasn't one.
+pub fn run(engine: Engine) -> i32 {
     let (event_loop, mut context) = engine;
+    let quit_state = context.quit_state();
     let mut main_loop = context
         .resources_mut()
         .remove::<MainLoopResource>()
         .unwrap_or(MainLoopResource::new(default_main_loop))
         .extract();
     main_loop.run((event_loop, context));
+    quit_state.exit_code()
 }
 
-pub(crate) fn default_main_loop(_engine: Engine) {}
+pub(crate) fn default_main_loop(engine: Engine) {
+    let mut driver = EngineDriver::new(engine);
+    if driver.stage().is_empty() && !driver.stage().is_loading() {
+        log::warn!("No initial scene was provided, so there is nothing to run.");
+        return;
+    }
+    while driver.on_new_events() {
+        driver.on_redraw();
+    }
+}
 
 #[cfg(test)]
 mod framework_runner_tests {
     use crate::main_loop::{MainLoopResource, MockMainLoop};
+    use crate::scenes::*;
 
     use super::*;
     use ntest::timeout;
@@ -66,4 +118,86 @@ mod framework_runner_tests {
         let engine = init().build().unwrap();
         run(engine);
     }
+
+    #[test]
+    #[timeout(100)]
+    fn default_main_loop_should_quit_when_the_stage_is_cleared() {
+        let mut scene = MockSceneTrait::new();
+        scene.expect_load().once().return_const(());
+        scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        scene
+            .expect_update()
+            .once()
+            .returning(|_| Some(SceneChange::Clear));
+        scene.expect_unload().once().return_const(());
+        let engine = init()
+            .with_initial_scene(Scene::new_unloaded(Box::from(scene)))
+            .build()
+            .unwrap();
+
+        run(engine);
+    }
+
+    #[test]
+    #[timeout(100)]
+    fn default_main_loop_should_update_and_render_the_stage() {
+        let mut scene = MockSceneTrait::new();
+        scene.expect_load().once().return_const(());
+        scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        let mut updates = 0;
+        scene.expect_update().times(3).returning(move |_| {
+            updates += 1;
+            if updates == 3 {
+                Some(SceneChange::Pop)
+            } else {
+                None
+            }
+        });
+        scene.expect_render().times(2).return_const(());
+        scene.expect_unload().once().return_const(());
+        let engine = init().with_scene(scene).build().unwrap();
+
+        run(engine);
+    }
+
+    #[test]
+    fn should_insert_initial_scene_resource() {
+        let (_event_loop, context) = init().with_scene(MockSceneTrait::new()).build().unwrap();
+
+        assert!(context.resources().get::<InitialSceneResource>().is_ok());
+    }
+
+    #[test]
+    #[timeout(100)]
+    fn should_return_the_exit_code() {
+        let mut scene = MockSceneTrait::new();
+        scene.expect_load().once().return_const(());
+        scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        scene.expect_update().once().returning(|context| {
+            context.quit_with(2);
+            None
+        });
+        scene.expect_render().return_const(());
+        scene.expect_unload().once().return_const(());
+        let engine = init().with_scene(scene).build().unwrap();
+
+        assert_eq!(run(engine), 2);
+    }
+
+    #[test]
+    #[timeout(100)]
+    fn should_return_an_exit_code_of_0_by_default() {
+        let engine = init().build().unwrap();
+
+        assert_eq!(run(engine), 0);
+    }
 }
@@ -1,4 +1,10 @@
-use wolf_engine_core::Engine;
+use std::time::{Duration, Instant};
+
+use wolf_engine_core::events::{EngineEvent, Event, EventLoop, EventReceiver, EventSender};
+use wolf_engine_core::{Context, Engine};
+
+use crate::input::{InputEvent, InputState};
+use crate::scenes::{state::Unloaded, Scene, SceneChangeQueue, Stage};
 
 /// An implementation of the engine's main-loop.
 #[cfg_attr(test, mockall::automock)]
@@ -35,3 +41,541 @@ impl MainLoopResource {
         self.inner
     }
 }
+
+/// Provides a wrapper around the [`Scene`] the default main-loop starts with, making it possible
+/// to access it as a [`Resource`] at run-time.
+///
+/// Insert this resource during setup to choose the first [`Scene`] pushed onto the
+/// [`Stage`](crate::scenes::Stage).
+pub struct InitialSceneResource {
+    scene: Scene<Unloaded>,
+}
+
+impl InitialSceneResource {
+    /// Creates a new resource from the provided [`Scene`].
+    pub fn new(scene: Scene<Unloaded>) -> Self {
+        Self { scene }
+    }
+
+    /// Consumes the resource, and returns the underlying [`Scene`].
+    pub fn extract(self) -> Scene<Unloaded> {
+        self.scene
+    }
+}
+
+/// Reports timing information about a rendered frame.
+///
+/// When enabled with [`FrameworkBuilder::with_frame_stats()`](crate::FrameworkBuilder), the
+/// [`EngineDriver`] sends one of these events after every redraw.  Once it comes back through the
+/// event loop, it's broadcast to every [`Scene`] on the [`Stage`], so profiling overlays, and
+/// other tools can pick it up in [`SceneTrait::on_event()`](crate::scenes::SceneTrait).
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct FrameStats {
+    /// The number of frames rendered so far, including this one.
+    pub frame: u64,
+
+    /// The time since the previous frame was rendered, or since the driver was created, for the
+    /// first frame.
+    pub frame_time: Duration,
+
+    /// The number of times the [`Stage`] was updated since the previous frame was rendered.
+    pub ticks: u32,
+
+    /// How far behind schedule the main-loop was when the frame started.
+    ///
+    /// Only main-loops running at a fixed rate, such as [`FixedRateMainLoop`], have a schedule to
+    /// fall behind, so this is always zero for other main-loops.
+    pub lag: Duration,
+
+    /// The lag, as a fraction of the main-loop's per-frame budget, from `0.0` to `1.0`.
+    ///
+    /// Renderers can use this to interpolate between updates.  Like the lag, it is always `0.0`
+    /// for main-loops which don't run at a fixed rate.
+    pub alpha: f32,
+}
+
+/// Indicates the [`EngineDriver`] should send [`FrameStats`] events.
+pub(crate) struct FrameStatsEnabled;
+
+struct FrameStatsTracker {
+    frame: u64,
+    last_frame: Instant,
+    ticks: u32,
+    lag: Duration,
+    alpha: f32,
+}
+
+impl FrameStatsTracker {
+    fn new() -> Self {
+        Self {
+            frame: 0,
+            last_frame: Instant::now(),
+            ticks: 0,
+            lag: Duration::ZERO,
+            alpha: 0.0,
+        }
+    }
+
+    fn next_frame(&mut self) -> FrameStats {
+        let now = Instant::now();
+        self.frame += 1;
+        let frame_time = now.duration_since(self.last_frame);
+        self.last_frame = now;
+        let frame_stats = FrameStats {
+            frame: self.frame,
+            frame_time,
+            ticks: self.ticks,
+            lag: self.lag,
+            alpha: self.alpha,
+        };
+        self.ticks = 0;
+        self.lag = Duration::ZERO;
+        self.alpha = 0.0;
+        frame_stats
+    }
+}
+
+/// Drives the [`Engine`], and a [`Stage`] from a main-loop owned by someone else.
+///
+/// Some frameworks, such as Winit, insist on being in control of the main-loop.  Instead of
+/// running its own loop, the driver exposes a set of callbacks, which the external main-loop
+/// calls when the matching thing happens:
+///
+/// - [`EngineDriver::on_new_events()`]: When new events are ready to be processed.  This
+///   processes the engine's queued events, forwarding any [`InputEvent`] to the [`InputState`],
+///   and the [`Stage`], then updates the [`Stage`].
+/// - [`EngineDriver::on_redraw()`]: When a frame should be drawn.  This renders the [`Stage`].
+/// - [`EngineDriver::on_window_event()`]: When a window event happens.  This broadcasts the event
+///   to every [`Scene`] on the [`Stage`].
+///
+/// The driver starts by inserting [`SceneChangeQueue`], and [`InputState`] resources, if they
+/// aren't there already, and pushing the initial [`Scene`], if one was provided.  It quits the engine
+/// once the [`Stage`] is empty, and unloads any remaining [`Scenes`](Scene) when it is dropped.
+pub struct EngineDriver {
+    event_loop: EventLoop,
+    context: Context,
+    stage: Stage,
+    frame_stats: Option<FrameStatsTracker>,
+}
+
+impl EngineDriver {
+    /// Creates a new driver from the provided [`Engine`].
+    pub fn new(engine: Engine) -> Self {
+        let (event_loop, mut context) = engine;
+        if context.resources().get::<SceneChangeQueue>().is_err() {
+            context.resources_mut().insert(SceneChangeQueue::new());
+        }
+        if context.resources().get::<InputState>().is_err() {
+            context.resources_mut().insert(InputState::new());
+        }
+        let frame_stats = context
+            .resources_mut()
+            .remove::<FrameStatsEnabled>()
+            .map(|_| FrameStatsTracker::new());
+        let mut stage = Stage::new();
+        if let Some(initial_scene) = context.resources_mut().remove::<InitialSceneResource>() {
+            stage.push(&mut context, initial_scene.extract());
+        }
+        Self {
+            event_loop,
+            context,
+            stage,
+            frame_stats,
+        }
+    }
+
+    /// Returns an immutable reference to the [`Context`].
+    pub fn context(&self) -> &Context {
+        &self.context
+    }
+
+    /// Returns a mutable reference to the [`Context`].
+    pub fn context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+
+    /// Returns an immutable reference to the [`Stage`].
+    pub fn stage(&self) -> &Stage {
+        &self.stage
+    }
+
+    /// Processes all queued events, and updates the [`Stage`] once.
+    ///
+    /// Returns `false` once the engine has quit, at which point the external main-loop should
+    /// stop, and drop the driver.
+    pub fn on_new_events(&mut self) -> bool {
+        if let Ok(mut input_state) = self.context.resources().get_mut::<InputState>() {
+            input_state.start_frame();
+        }
+        while let Some(event) = self.event_loop.next_event() {
+            if let Some(input) = event.downcast_ref::<InputEvent>() {
+                if let Ok(mut input_state) = self.context.resources().get_mut::<InputState>() {
+                    input_state.handle_event(input);
+                }
+                self.stage.handle_input(&mut self.context, input);
+            } else if let Some(frame_stats) = event.downcast_ref::<FrameStats>() {
+                self.stage.broadcast_event(&mut self.context, frame_stats);
+            } else if let Some(EngineEvent::EventsCleared) = event.downcast_ref::<EngineEvent>() {
+                self.stage.update(&mut self.context);
+                if let Some(frame_stats) = self.frame_stats.as_mut() {
+                    frame_stats.ticks += 1;
+                }
+                if self.stage.is_empty()
+                    && !self.stage.is_loading()
+                    && !self.stage.is_transitioning()
+                {
+                    self.context.quit();
+                }
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Renders the [`Stage`].
+    ///
+    /// If [`FrameStats`] are enabled, they are sent once the frame has been rendered.
+    pub fn on_redraw(&mut self) {
+        self.stage.render(&mut self.context);
+        if let Some(frame_stats) = self.frame_stats.as_mut() {
+            let _ = self
+                .context
+                .event_sender()
+                .send_event(Box::from(frame_stats.next_frame()));
+        }
+    }
+
+    /// Records how far behind schedule the main-loop is, for the next [`FrameStats`] event.
+    ///
+    /// The interpolation alpha is the lag as a fraction of the `frame_budget`.
+    pub(crate) fn record_frame_lag(&mut self, lag: Duration, frame_budget: Duration) {
+        if let Some(frame_stats) = self.frame_stats.as_mut() {
+            frame_stats.lag = lag;
+            frame_stats.alpha = (lag.as_secs_f32() / frame_budget.as_secs_f32()).min(1.0);
+        }
+    }
+
+    /// Broadcasts a window event to every [`Scene`] on the [`Stage`].
+    pub fn on_window_event(&mut self, event: &dyn Event) {
+        self.stage.broadcast_event(&mut self.context, event);
+    }
+}
+
+impl Drop for EngineDriver {
+    fn drop(&mut self) {
+        self.stage.clear(&mut self.context);
+    }
+}
+
+/// A [`MainLoop`] which hands control over to an external main-loop.
+///
+/// Instead of running a loop itself, the provided function is given an [`EngineDriver`], which it
+/// is expected to pump from the external main-loop.
+pub struct ExternalMainLoop<F: FnMut(EngineDriver)> {
+    runner: F,
+}
+
+impl<F: FnMut(EngineDriver)> ExternalMainLoop<F> {
+    /// Creates a new main-loop, which runs the engine through the provided function.
+    pub fn new(runner: F) -> Self {
+        Self { runner }
+    }
+}
+
+impl<F: FnMut(EngineDriver)> MainLoop for ExternalMainLoop<F> {
+    fn run(&mut self, engine: Engine) {
+        (self.runner)(EngineDriver::new(engine))
+    }
+}
+
+/// A [`MainLoop`] which runs at a fixed real-time rate.
+///
+/// Each iteration updates, and renders the [`Stage`], then sleeps for whatever is left of the
+/// per-iteration budget, instead of busy-looping.  Iterations are scheduled against a running
+/// deadline, so small overruns are made up for by shorter sleeps.  If an iteration falls more than
+/// a whole budget behind, the deadline is reset rather than trying to catch up.
+///
+/// # Examples
+///
+/// ```
+/// # use wolf_engine_framework::main_loop::FixedRateMainLoop;
+/// # use wolf_engine_framework::FrameworkBuilder;
+/// #
+/// let engine = wolf_engine_core::init()
+///     .with_main_loop(FixedRateMainLoop::new(60))
+///     .build()
+///     .unwrap();
+/// ```
+pub struct FixedRateMainLoop {
+    target_fps: u32,
+}
+
+impl FixedRateMainLoop {
+    /// Creates a new main-loop, which runs `target_fps` iterations per second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `target_fps` is `0`.
+    pub fn new(target_fps: u32) -> Self {
+        assert!(target_fps > 0, "The target FPS must be greater than 0");
+        Self { target_fps }
+    }
+
+    /// Returns the number of iterations per second the main-loop runs at.
+    pub fn target_fps(&self) -> u32 {
+        self.target_fps
+    }
+
+    /// Returns the time budget for a single iteration.
+    pub fn frame_budget(&self) -> Duration {
+        Duration::from_secs(1) / self.target_fps
+    }
+}
+
+impl MainLoop for FixedRateMainLoop {
+    fn run(&mut self, engine: Engine) {
+        let frame_budget = self.frame_budget();
+        let mut driver = EngineDriver::new(engine);
+        let mut deadline = Instant::now();
+        while driver.on_new_events() {
+            driver.record_frame_lag(
+                Instant::now().saturating_duration_since(deadline),
+                frame_budget,
+            );
+            driver.on_redraw();
+            deadline += frame_budget;
+            let now = Instant::now();
+            if deadline > now {
+                std::thread::sleep(deadline - now);
+            } else if now - deadline > frame_budget {
+                deadline = now;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod engine_driver_tests {
+    use ntest::timeout;
+    use wolf_engine_core::events::{Event, EventSender};
+
+    use super::*;
+    use crate::input::Key;
+    use crate::scenes::*;
+    use crate::FrameworkBuilder;
+
+    fn loaded_scene() -> MockSceneTrait {
+        let mut scene = MockSceneTrait::new();
+        scene.expect_load().once().return_const(());
+        scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        scene.expect_unload().once().return_const(());
+        scene
+    }
+
+    #[test]
+    fn should_render_once_per_redraw() {
+        let mut scene = loaded_scene();
+        scene.expect_render().once().return_const(());
+        let engine = wolf_engine_core::init().with_scene(scene).build().unwrap();
+        let mut driver = EngineDriver::new(engine);
+
+        driver.on_redraw();
+    }
+
+    #[test]
+    fn should_update_once_per_new_events() {
+        let mut scene = loaded_scene();
+        scene.expect_update().times(2).returning(|_| None);
+        let engine = wolf_engine_core::init().with_scene(scene).build().unwrap();
+        let mut driver = EngineDriver::new(engine);
+
+        assert!(driver.on_new_events());
+        assert!(driver.on_new_events());
+    }
+
+    #[derive(Event, Debug)]
+    struct TestWindowEvent;
+
+    #[test]
+    fn should_broadcast_window_events() {
+        let mut scene = loaded_scene();
+        scene.expect_on_event().once().return_const(());
+        let engine = wolf_engine_core::init().with_scene(scene).build().unwrap();
+        let mut driver = EngineDriver::new(engine);
+
+        driver.on_window_event(&TestWindowEvent);
+    }
+
+    #[test]
+    #[timeout(100)]
+    fn should_run_through_an_external_main_loop() {
+        let mut scene = loaded_scene();
+        scene
+            .expect_update()
+            .once()
+            .returning(|_| Some(SceneChange::Pop));
+        let engine = wolf_engine_core::init()
+            .with_scene(scene)
+            .with_main_loop(ExternalMainLoop::new(|mut driver: EngineDriver| {
+                while driver.on_new_events() {
+                    driver.on_redraw();
+                }
+            }))
+            .build()
+            .unwrap();
+
+        crate::run(engine);
+    }
+
+    #[test]
+    fn should_forward_input_events_to_the_stage() {
+        let mut scene = loaded_scene();
+        scene
+            .expect_handle_input()
+            .once()
+            .withf(|_, input| *input == InputEvent::KeyDown(Key::Space))
+            .returning(|_, _| None);
+        scene.expect_update().once().returning(|_| None);
+        let engine = wolf_engine_core::init().with_scene(scene).build().unwrap();
+        let mut driver = EngineDriver::new(engine);
+        driver
+            .context()
+            .event_sender()
+            .send_event(Box::from(InputEvent::KeyDown(Key::Space)))
+            .unwrap();
+
+        driver.on_new_events();
+    }
+
+    #[test]
+    fn should_update_the_input_state() {
+        let mut scene = loaded_scene();
+        scene.expect_handle_input().returning(|_, _| None);
+        scene.expect_update().times(2).returning(|_| None);
+        let engine = wolf_engine_core::init().with_scene(scene).build().unwrap();
+        let mut driver = EngineDriver::new(engine);
+        driver
+            .context()
+            .event_sender()
+            .send_event(Box::from(InputEvent::KeyDown(Key::W)))
+            .unwrap();
+
+        driver.on_new_events();
+        {
+            let input_state = driver.context().resources().get::<InputState>().unwrap();
+            assert!(input_state.is_key_down(Key::W));
+            assert!(input_state.just_pressed(Key::W));
+        }
+
+        driver.on_new_events();
+        let input_state = driver.context().resources().get::<InputState>().unwrap();
+        assert!(input_state.is_key_down(Key::W));
+        assert!(!input_state.just_pressed(Key::W));
+    }
+
+    #[test]
+    #[timeout(1000)]
+    fn fixed_rate_main_loop_should_run_at_the_target_rate() {
+        let mut scene = loaded_scene();
+        let mut updates = 0;
+        scene.expect_update().times(20).returning(move |_| {
+            updates += 1;
+            if updates == 20 {
+                Some(SceneChange::Pop)
+            } else {
+                None
+            }
+        });
+        scene.expect_render().times(19).return_const(());
+        let engine = wolf_engine_core::init()
+            .with_scene(scene)
+            .with_main_loop(FixedRateMainLoop::new(100))
+            .build()
+            .unwrap();
+
+        let start = Instant::now();
+        crate::run(engine);
+        let elapsed = start.elapsed();
+
+        assert!(
+            elapsed >= Duration::from_millis(150) && elapsed <= Duration::from_millis(400),
+            "20 iterations at 100 FPS should take about 200ms, but took {:?}",
+            elapsed
+        );
+    }
+
+    #[test]
+    fn should_send_frame_stats_once_per_redraw_when_enabled() {
+        let mut scene = loaded_scene();
+        scene.expect_update().times(2).returning(|_| None);
+        scene.expect_render().once().return_const(());
+        scene
+            .expect_on_event()
+            .once()
+            .withf(|_, event| {
+                let frame_stats = event.downcast_ref::<FrameStats>().unwrap();
+                frame_stats.frame == 1
+                    && frame_stats.frame_time < Duration::from_secs(10)
+                    && frame_stats.ticks == 1
+                    && frame_stats.lag == Duration::ZERO
+                    && frame_stats.alpha == 0.0
+            })
+            .return_const(());
+        let engine = wolf_engine_core::init()
+            .with_scene(scene)
+            .with_frame_stats()
+            .build()
+            .unwrap();
+        let mut driver = EngineDriver::new(engine);
+
+        driver.on_new_events();
+        driver.on_redraw();
+        driver.on_new_events();
+    }
+
+    #[test]
+    fn should_report_ticks_and_lag_in_frame_stats() {
+        let mut scene = loaded_scene();
+        scene.expect_update().times(3).returning(|_| None);
+        scene.expect_render().once().return_const(());
+        scene
+            .expect_on_event()
+            .once()
+            .withf(|_, event| {
+                let frame_stats = event.downcast_ref::<FrameStats>().unwrap();
+                frame_stats.ticks == 2
+                    && frame_stats.lag == Duration::from_millis(8)
+                    && frame_stats.alpha == 0.5
+            })
+            .return_const(());
+        let engine = wolf_engine_core::init()
+            .with_scene(scene)
+            .with_frame_stats()
+            .build()
+            .unwrap();
+        let mut driver = EngineDriver::new(engine);
+
+        driver.on_new_events();
+        driver.on_new_events();
+        driver.record_frame_lag(Duration::from_millis(8), Duration::from_millis(16));
+        driver.on_redraw();
+        driver.on_new_events();
+    }
+
+    #[test]
+    fn should_not_send_frame_stats_by_default() {
+        let mut scene = loaded_scene();
+        scene.expect_update().times(2).returning(|_| None);
+        scene.expect_render().once().return_const(());
+        scene.expect_on_event().never();
+        let engine = wolf_engine_core::init().with_scene(scene).build().unwrap();
+        let mut driver = EngineDriver::new(engine);
+
+        driver.on_new_events();
+        driver.on_redraw();
+        driver.on_new_events();
+    }
+}
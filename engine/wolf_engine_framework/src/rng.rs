@@ -0,0 +1,117 @@
+//! Provides a reproducible source of randomness for games.
+
+use rand::distributions::uniform::{SampleRange, SampleUniform};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+/// Provides a single, seedable source of randomness.
+///
+/// Routing all of a game's randomness through one `RngContext` resource, rather than calling
+/// `rand::thread_rng()` all over the place, makes it reproducible.  Two `RngContext`s created
+/// with the same seed will always produce the same sequence of values, which is useful for tests,
+/// and replays.
+///
+/// # Examples
+///
+/// ```
+/// # use wolf_engine_framework::rng::RngContext;
+/// #
+/// let mut rng = RngContext::new(42);
+/// let roll = rng.gen_range(1..=6);
+///
+/// assert!((1..=6).contains(&roll));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RngContext {
+    rng: SmallRng,
+}
+
+impl RngContext {
+    /// Creates a new `RngContext` with the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: SmallRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Reseeds the `RngContext`, restarting its sequence from the given seed.
+    pub fn seed(&mut self, seed: u64) {
+        self.rng = SmallRng::seed_from_u64(seed);
+    }
+
+    /// Returns a random value within the given range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    pub fn gen_range<T: SampleUniform, R: SampleRange<T>>(&mut self, range: R) -> T {
+        self.rng.gen_range(range)
+    }
+
+    /// Returns a random `f32` in the range `0.0..1.0`.
+    pub fn next_f32(&mut self) -> f32 {
+        self.rng.gen()
+    }
+}
+
+/// Creates an `RngContext` seeded from the operating system's entropy source.
+impl Default for RngContext {
+    fn default() -> Self {
+        Self {
+            rng: SmallRng::from_entropy(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod rng_context_tests {
+    use super::*;
+
+    fn sequence(rng: &mut RngContext) -> Vec<u32> {
+        (0..16).map(|_| rng.gen_range(0..1000)).collect()
+    }
+
+    #[test]
+    fn should_produce_identical_sequences_from_identical_seeds() {
+        let mut a = RngContext::new(42);
+        let mut b = RngContext::new(42);
+
+        assert_eq!(sequence(&mut a), sequence(&mut b));
+        assert_eq!(a.next_f32(), b.next_f32());
+    }
+
+    #[test]
+    fn should_restart_the_sequence_when_reseeded() {
+        let mut rng = RngContext::new(42);
+        let first = sequence(&mut rng);
+
+        rng.seed(42);
+
+        assert_eq!(sequence(&mut rng), first);
+    }
+
+    #[test]
+    fn should_generate_values_within_range() {
+        let mut rng = RngContext::new(7);
+
+        for _ in 0..100 {
+            let value = rng.next_f32();
+            assert!((0.0..1.0).contains(&value));
+            assert!((-5..5).contains(&rng.gen_range(-5..5)));
+        }
+    }
+
+    #[test]
+    fn should_be_usable_as_a_resource() {
+        let (_event_loop, context) = wolf_engine_core::init()
+            .with_resource(RngContext::new(1))
+            .build()
+            .unwrap();
+
+        let value = context
+            .with_resource_mut(|rng: &mut RngContext| rng.gen_range(0..10))
+            .unwrap();
+
+        assert!((0..10).contains(&value));
+    }
+}
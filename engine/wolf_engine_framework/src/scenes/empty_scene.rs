@@ -0,0 +1,70 @@
+use wolf_engine_core::Context;
+
+use crate::scenes::{SceneChange, SceneTrait};
+
+/// A [`SceneTrait`] implementation which does nothing.
+///
+/// This is mostly useful for examples, and tests which need a scene, but don't care what it does.
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct EmptyScene;
+
+impl SceneTrait for EmptyScene {
+    fn update(&mut self, _context: &mut Context) -> Option<SceneChange> {
+        None
+    }
+
+    fn render(&mut self, _context: &mut Context) {}
+}
+
+/// A [`SceneTrait`] implementation which [clears](SceneChange::Clear) the
+/// [`Stage`](crate::scenes::Stage) on its first update.
+///
+/// This is mostly useful for examples, and tests which need the engine to quit right away.
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct QuitScene;
+
+impl SceneTrait for QuitScene {
+    fn update(&mut self, _context: &mut Context) -> Option<SceneChange> {
+        Some(SceneChange::Clear)
+    }
+
+    fn render(&mut self, _context: &mut Context) {}
+}
+
+#[cfg(test)]
+mod empty_scene_tests {
+    use super::*;
+    use crate::scenes::{Scene, Stage};
+
+    #[test]
+    fn empty_scene_should_stay_on_the_stage() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+        stage.push(&mut context, Scene::new_unloaded(Box::from(EmptyScene)));
+
+        stage.update(&mut context);
+        stage.render(&mut context);
+
+        assert_eq!(stage.len(), 1);
+    }
+
+    #[test]
+    fn quit_scene_should_clear_the_stage() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+        stage.push(&mut context, Scene::new_unloaded(Box::from(EmptyScene)));
+        stage.push(&mut context, Scene::new_unloaded(Box::from(QuitScene)));
+
+        stage.update(&mut context);
+
+        assert!(stage.is_empty());
+    }
+
+    #[test]
+    fn should_be_named_after_the_scene_type() {
+        assert!(EmptyScene.name().ends_with("EmptyScene"));
+        assert!(QuitScene.name().ends_with("QuitScene"));
+    }
+}
@@ -1,6 +1,14 @@
 //! Provides a scene system for the engine.
 
+mod empty_scene;
+pub use empty_scene::*;
 mod scene;
 pub use scene::*;
+mod scene_clock;
+pub use scene_clock::*;
+mod scene_registry;
+pub use scene_registry::*;
+mod scene_transition;
+pub use scene_transition::*;
 mod stage;
 pub use stage::*;
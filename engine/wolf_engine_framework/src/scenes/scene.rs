@@ -1,10 +1,24 @@
 use std::marker::PhantomData;
+use std::time::{Duration, Instant};
 
+use wolf_engine_core::events::Event;
 use wolf_engine_core::Context;
 
+use crate::input::InputEvent;
+
 /// An alias for a [Boxed](Box) [`SceneTrait`].
 pub type SceneBox = Box<dyn SceneTrait>;
 
+/// Reports the progress of a [`Scene`] which is still loading.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LoadProgress {
+    /// The scene is still loading, and is about the given fraction (`0.0` to `1.0`) done.
+    InProgress(f32),
+
+    /// The scene has finished loading.
+    Done,
+}
+
 /// The user-facing trait used to provide the functions of a [`Scene`].
 #[allow(unused)]
 #[cfg_attr(test, mockall::automock)]
@@ -21,14 +35,89 @@ pub trait SceneTrait {
     /// Runs all setup operations for the scene.
     fn load(&mut self, context: &mut Context) {}
 
+    /// Continues loading the scene, and reports the current progress.
+    ///
+    /// After [`SceneTrait::load()`] is called, this method is called repeatedly (typically once
+    /// per frame) until it returns [`LoadProgress::Done`], so long-running work can be split
+    /// across several frames.  By default, scenes are done loading immediately.
+    fn poll_load(&mut self, context: &mut Context) -> LoadProgress {
+        LoadProgress::Done
+    }
+
     /// Runs all shutdown operations for the scene.
     fn unload(&mut self, context: &mut Context) {}
 
+    /// Runs when the scene stops being the active scene, because another scene was pushed on
+    /// top of it.
+    fn pause(&mut self, context: &mut Context) {}
+
+    /// Runs when the scene becomes the active scene again, because the scene above it was popped.
+    fn resume(&mut self, context: &mut Context) {}
+
     /// Updates the current state when the scene is in the background.
     fn background_update(&mut self, context: &mut Context) {}
 
+    /// Returns the minimum time between [background updates](SceneTrait::background_update()).
+    ///
+    /// Background scenes which don't need to update every frame, such as a paused world, can
+    /// return an interval here to save some work.  By default, `None` is returned, and the scene
+    /// is updated every frame.
+    fn background_update_interval(&self) -> Option<Duration> {
+        None
+    }
+
     /// Renders the current game state when the scene is in the background.
     fn background_render(&mut self, context: &mut Context) {}
+
+    /// Returns `true` if [`SceneTrait::background_render()`] should be called.
+    ///
+    /// Background scenes which rarely change, such as a paused world behind a menu, can return
+    /// `false` here to skip redundant draws.  Background updates still run as normal.  By default,
+    /// `true` is returned, and the scene is rendered every frame.
+    fn should_background_render(&self) -> bool {
+        true
+    }
+
+    /// Handles an [`InputEvent`] when the scene is active.
+    ///
+    /// Background scenes never receive input.  Like active updates, input handling can
+    /// optionally return a [`SceneChange`](crate::scenes::SceneChange) to change scenes.
+    fn handle_input(&mut self, context: &mut Context, input: &InputEvent) -> Option<SceneChange> {
+        None
+    }
+
+    /// Handles an [`Event`] broadcast to every scene on the [`Stage`](crate::scenes::Stage),
+    /// whether it is active, or not.
+    fn on_event(&mut self, context: &mut Context, event: &dyn Event) {}
+
+    /// Returns a people-friendly name for the scene.
+    ///
+    /// This is mostly useful for debugging tools, such as overlays showing the active scene.  By
+    /// default, the scene's type name is returned.
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+}
+
+/// Converts a value into an [`Unloaded`] [`Scene`].
+///
+/// This is implemented for every [`SceneTrait`] object, so they can be used without having to box,
+/// and wrap them by hand.
+pub trait IntoScene {
+    /// Wraps the value in an [`Unloaded`] [`Scene`].
+    fn into_scene(self) -> Scene<Unloaded>;
+}
+
+impl<T: SceneTrait + 'static> IntoScene for T {
+    fn into_scene(self) -> Scene<Unloaded> {
+        Scene::new_unloaded(Box::from(self))
+    }
+}
+
+impl IntoScene for Scene<Unloaded> {
+    fn into_scene(self) -> Scene<Unloaded> {
+        self
+    }
 }
 
 /// Provides type-state structs used by the [`Scene`].
@@ -36,13 +125,17 @@ pub mod state {
     /// A [`Scene`](super::Scene) type-state indicating the scene has not yet been loaded.
     pub struct Unloaded;
 
+    /// A [`Scene`](super::Scene) type-state indicating the scene has started, but not yet
+    /// finished loading.
+    pub struct Loading;
+
     /// A [`Scene`](super::Scene) type-state indicating the scene has been loaded.
     pub struct Loaded;
 }
 
 use state::*;
 
-use super::SceneChange;
+use super::{SceneChange, SceneClock};
 
 /// Provides the systems, and holds the assets, resources, ext. used by a game.
 ///
@@ -58,30 +151,105 @@ use super::SceneChange;
 /// Once in the [`Loaded`] state, the rest of the Scene's methods, save for  [`Scene::load()`], are
 /// made accessible.
 ///
+/// Scenes which take a while to load can instead be put into the [`Loading`] state with
+/// [`Scene::start_loading()`].  A [`Loading`] Scene is [polled](Scene::poll_load()) until it
+/// reports [`LoadProgress::Done`], then [finished](Scene::finish_loading()).
+///
 /// Running the [`Scene::unload()`] method will consume the Scene, running it's one-time shutdown,
 /// and dropping the Scene.
+///
+/// Every [`Loaded`] Scene keeps a [`SceneClock`], which is reset when the Scene is loaded.  While
+/// the Scene is updated, rendered, given input, or handling events, a copy of its clock is
+/// available as a resource.  Any [`SceneClock`] resource which was there before is put back once
+/// the call returns.
 pub struct Scene<State = Unloaded> {
     inner: SceneBox,
+    last_background_update: Option<Instant>,
+    clock: SceneClock,
     _state: PhantomData<State>,
 }
 
+impl<State> Scene<State> {
+    /// Returns the [name](SceneTrait::name()) of the scene.
+    pub fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+}
+
 impl Scene<Unloaded> {
     /// Creates a new Scene, in the [`Unloaded`] state, with the provided [`SceneTrait`].
     pub fn new_unloaded(inner: SceneBox) -> Scene<Unloaded> {
         Scene::<Unloaded> {
             inner,
+            last_background_update: None,
+            clock: SceneClock::new(),
             _state: PhantomData,
         }
     }
 
     /// Loads the Scene, and puts it into the [`Loaded`] state.
-    pub fn load(mut self, context: &mut Context) -> Scene<Loaded> {
+    ///
+    /// This method blocks until the Scene is done loading, or the `timeout` has elapsed, yielding
+    /// the thread between polls.  If the Scene is still loading when the timeout runs out, it's
+    /// returned as an error in the [`Loading`] state, so it can be polled further, or
+    /// [unloaded](Scene::<Loading>::unload()).
+    ///
+    /// Scenes which take a while to load should be [pushed](crate::scenes::Stage::push()) onto a
+    /// [`Stage`](crate::scenes::Stage) instead, which polls them without blocking.
+    pub fn load(
+        self,
+        context: &mut Context,
+        timeout: Duration,
+    ) -> Result<Scene<Loaded>, Scene<Loading>> {
+        let started = Instant::now();
+        let mut scene = self.start_loading(context);
+        loop {
+            if scene.poll_load(context) == LoadProgress::Done {
+                return Ok(scene.finish_loading());
+            }
+            if started.elapsed() >= timeout {
+                return Err(scene);
+            }
+            std::thread::yield_now();
+        }
+    }
+
+    /// Starts loading the Scene, and puts it into the [`Loading`] state.
+    pub fn start_loading(mut self, context: &mut Context) -> Scene<Loading> {
         self.inner.load(context);
+        Scene::<Loading> {
+            inner: self.inner,
+            last_background_update: None,
+            clock: SceneClock::new(),
+            _state: PhantomData,
+        }
+    }
+}
+
+impl Scene<Loading> {
+    /// Continues loading the Scene, and reports the current progress.
+    pub fn poll_load(&mut self, context: &mut Context) -> LoadProgress {
+        self.inner.poll_load(context)
+    }
+
+    /// Puts the Scene into the [`Loaded`] state.
+    ///
+    /// This should only be called once [`Scene::poll_load()`] has reported
+    /// [`LoadProgress::Done`].
+    pub fn finish_loading(self) -> Scene<Loaded> {
         Scene::<Loaded> {
             inner: self.inner,
+            last_background_update: None,
+            clock: SceneClock::new(),
             _state: PhantomData,
         }
     }
+
+    /// Unloads the scene before it has finished loading, consuming, and dropping it in the
+    /// process.
+    pub fn unload(mut self, context: &mut Context) {
+        self.inner.unload(context)
+    }
 }
 
 impl Scene<Loaded> {
@@ -90,21 +258,98 @@ impl Scene<Loaded> {
     /// Active updates can optionally return a [`SceneChange`](crate::scenes::SceneChange), to the
     /// [`Stage`](crate::scenes::Stage) to change scenes.
     pub fn update(&mut self, context: &mut Context) -> Option<SceneChange> {
-        self.inner.update(context)
+        self.with_clock(context, |inner, context| inner.update(context))
     }
     /// Renders the current game state when the scene is active.
     pub fn render(&mut self, context: &mut Context) {
-        self.inner.render(context)
+        self.with_clock(context, |inner, context| inner.render(context))
     }
 
     /// Updates the current state when the scene is in the background.
+    ///
+    /// If the scene has a [background update
+    /// interval](SceneTrait::background_update_interval()), the update is skipped until the
+    /// interval has elapsed since the last one.
     pub fn background_update(&mut self, context: &mut Context) {
-        self.inner.background_update(context)
+        if !self.start_background_update(Instant::now()) {
+            return;
+        }
+        self.with_clock(context, |inner, context| inner.background_update(context))
+    }
+
+    /// Returns `true` if a background update is due at `now`, and records it as the last one.
+    fn start_background_update(&mut self, now: Instant) -> bool {
+        let interval = match self.inner.background_update_interval() {
+            Some(interval) => interval,
+            None => return true,
+        };
+        if let Some(last_update) = self.last_background_update {
+            if now.duration_since(last_update) < interval {
+                return false;
+            }
+        }
+        self.last_background_update = Some(now);
+        true
     }
 
     /// Renders the current state when the scene is in the background.
+    ///
+    /// The render is skipped if the scene [shouldn't be rendered in the
+    /// background](SceneTrait::should_background_render()).
     pub fn background_render(&mut self, context: &mut Context) {
-        self.inner.background_render(context)
+        if !self.inner.should_background_render() {
+            return;
+        }
+        self.with_clock(context, |inner, context| inner.background_render(context))
+    }
+
+    /// Handles an [`InputEvent`] when the scene is active.
+    pub fn handle_input(
+        &mut self,
+        context: &mut Context,
+        input: &InputEvent,
+    ) -> Option<SceneChange> {
+        self.with_clock(context, |inner, context| inner.handle_input(context, input))
+    }
+
+    /// Handles a broadcast [`Event`].
+    pub fn on_event(&mut self, context: &mut Context, event: &dyn Event) {
+        self.with_clock(context, |inner, context| inner.on_event(context, event))
+    }
+
+    /// Runs when the scene stops being the active scene.
+    ///
+    /// The scene's [`SceneClock`] is stopped.
+    pub fn pause(&mut self, context: &mut Context) {
+        self.clock.stop();
+        self.inner.pause(context)
+    }
+
+    /// Runs when the scene becomes the active scene again.
+    ///
+    /// The scene's [`SceneClock`] is started again.
+    pub fn resume(&mut self, context: &mut Context) {
+        self.clock.start();
+        self.inner.resume(context)
+    }
+
+    /// Returns the scene's [`SceneClock`].
+    pub fn clock(&self) -> &SceneClock {
+        &self.clock
+    }
+
+    pub(crate) fn clock_mut(&mut self) -> &mut SceneClock {
+        &mut self.clock
+    }
+
+    /// Runs `f` with a copy of the scene's clock scoped into the context.
+    fn with_clock<T>(
+        &mut self,
+        context: &mut Context,
+        f: impl FnOnce(&mut SceneBox, &mut Context) -> T,
+    ) -> T {
+        let mut context = context.with_scoped_resource(self.clock);
+        f(&mut self.inner, &mut context)
     }
 
     /// Unloads the scene, consuming, and dropping it in the process.
@@ -122,10 +367,129 @@ mod scene_tests {
         let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
         let mut inner = MockSceneTrait::new();
         inner.expect_load().once().return_const(());
+        inner
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
         inner.expect_unload().once().return_const(());
         let scene = Scene::new_unloaded(Box::from(inner));
 
-        let loaded_scene = scene.load(&mut context);
+        let loaded_scene = scene
+            .load(&mut context, Duration::from_secs(1))
+            .unwrap_or_else(|_| panic!("The scene should have loaded"));
         loaded_scene.unload(&mut context);
     }
+
+    #[test]
+    fn load_should_block_until_the_scene_is_done_loading() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut inner = MockSceneTrait::new();
+        inner.expect_load().once().return_const(());
+        let mut polls = 0;
+        inner.expect_poll_load().times(3).returning(move |_| {
+            polls += 1;
+            if polls < 3 {
+                LoadProgress::InProgress(polls as f32 / 3.0)
+            } else {
+                LoadProgress::Done
+            }
+        });
+        let scene = Scene::new_unloaded(Box::from(inner));
+
+        let _loaded_scene = scene
+            .load(&mut context, Duration::from_secs(1))
+            .unwrap_or_else(|_| panic!("The scene should have loaded"));
+    }
+
+    #[test]
+    fn load_should_give_up_once_the_timeout_has_elapsed() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut inner = MockSceneTrait::new();
+        inner.expect_load().once().return_const(());
+        inner
+            .expect_poll_load()
+            .return_const(LoadProgress::InProgress(0.5));
+        inner.expect_unload().once().return_const(());
+        let scene = Scene::new_unloaded(Box::from(inner));
+
+        let loading_scene = scene
+            .load(&mut context, Duration::ZERO)
+            .err()
+            .expect("The scene should still be loading");
+        loading_scene.unload(&mut context);
+    }
+
+    #[test]
+    fn should_throttle_background_updates_to_the_interval() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut inner = MockSceneTrait::new();
+        inner.expect_load().once().return_const(());
+        inner
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        inner
+            .expect_background_update_interval()
+            .return_const(Some(Duration::from_millis(100)));
+        let mut scene = Scene::new_unloaded(Box::from(inner))
+            .load(&mut context, Duration::from_secs(1))
+            .unwrap_or_else(|_| panic!("The scene should have loaded"));
+        let start = Instant::now();
+
+        assert!(scene.start_background_update(start));
+        assert!(!scene.start_background_update(start + Duration::from_millis(50)));
+        assert!(!scene.start_background_update(start + Duration::from_millis(99)));
+        assert!(scene.start_background_update(start + Duration::from_millis(100)));
+        assert!(!scene.start_background_update(start + Duration::from_millis(150)));
+        assert!(scene.start_background_update(start + Duration::from_millis(250)));
+    }
+
+    #[derive(Event, Debug)]
+    struct TestEvent;
+
+    fn assert_clock_is_running(context: &mut Context) {
+        let clock = context.resources().get::<SceneClock>().unwrap();
+        assert!(clock.is_running(), "The scene's clock should be shared");
+    }
+
+    #[test]
+    fn should_scope_the_scene_clock_to_each_call() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        context.add_or_replace(SceneClock::new());
+        let mut inner = MockSceneTrait::new();
+        inner.expect_load().once().return_const(());
+        inner
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        inner.expect_update().once().returning(|context| {
+            assert_clock_is_running(context);
+            None
+        });
+        inner
+            .expect_on_event()
+            .once()
+            .returning(|context, _| assert_clock_is_running(context));
+        let mut scene = Scene::new_unloaded(Box::from(inner))
+            .load(&mut context, Duration::from_secs(1))
+            .unwrap_or_else(|_| panic!("The scene should have loaded"));
+        scene.clock_mut().start();
+
+        scene.update(&mut context);
+        assert!(!context
+            .resources()
+            .get::<SceneClock>()
+            .unwrap()
+            .is_running());
+        scene.on_event(&mut context, &TestEvent);
+
+        assert!(
+            !context
+                .resources()
+                .get::<SceneClock>()
+                .unwrap()
+                .is_running(),
+            "The previous clock should be put back"
+        );
+    }
 }
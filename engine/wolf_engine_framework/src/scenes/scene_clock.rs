@@ -0,0 +1,123 @@
+use std::time::{Duration, Instant};
+
+/// Tracks how long a [`Scene`](crate::scenes::Scene) has been the active scene.
+///
+/// Unlike global time, a `SceneClock` only advances while its scene is active.  It stops while the
+/// scene is in the background, such as the world behind a pause menu, and picks up where it left
+/// off once the scene is active again.  This makes it a good fit for animations, and timers which
+/// should freeze while the scene is paused.
+///
+/// The [`Stage`](crate::scenes::Stage) keeps a clock for every scene, and resets it when the scene
+/// is loaded.  While a scene is being run, a copy of its clock is available as a resource.
+///
+/// # Examples
+///
+/// ```
+/// # use wolf_engine_core::Context;
+/// # use wolf_engine_framework::scenes::*;
+/// #
+/// fn update(context: &mut Context) -> Option<SceneChange> {
+///     let time_active = context.resources().get::<SceneClock>().unwrap().elapsed();
+///     log::info!("This scene has been active for {:?}", time_active);
+///     None
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SceneClock {
+    accumulated: Duration,
+    running_since: Option<Instant>,
+}
+
+impl SceneClock {
+    /// Creates a new, stopped clock, with no elapsed time.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the total time the clock has been running for.
+    pub fn elapsed(&self) -> Duration {
+        match self.running_since {
+            Some(running_since) => self.accumulated + running_since.elapsed(),
+            None => self.accumulated,
+        }
+    }
+
+    /// Returns `true` if the clock is currently advancing.
+    pub fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    /// Starts, or resumes the clock.  Does nothing if the clock is already running.
+    pub fn start(&mut self) {
+        if self.running_since.is_none() {
+            self.running_since = Some(Instant::now());
+        }
+    }
+
+    /// Stops the clock, keeping the time elapsed so far.  Does nothing if the clock is already
+    /// stopped.
+    pub fn stop(&mut self) {
+        if let Some(running_since) = self.running_since.take() {
+            self.accumulated += running_since.elapsed();
+        }
+    }
+
+    /// Stops the clock, and sets the elapsed time back to zero.
+    pub fn reset(&mut self) {
+        self.accumulated = Duration::ZERO;
+        self.running_since = None;
+    }
+}
+
+#[cfg(test)]
+mod scene_clock_tests {
+    use super::*;
+
+    #[test]
+    fn should_start_stopped_at_zero() {
+        let clock = SceneClock::new();
+
+        assert!(!clock.is_running());
+        assert_eq!(clock.elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn should_only_advance_while_running() {
+        let mut clock = SceneClock::new();
+
+        clock.start();
+        std::thread::sleep(Duration::from_millis(2));
+        clock.stop();
+        let elapsed = clock.elapsed();
+        std::thread::sleep(Duration::from_millis(2));
+
+        assert!(elapsed >= Duration::from_millis(2));
+        assert_eq!(clock.elapsed(), elapsed);
+    }
+
+    #[test]
+    fn should_keep_elapsed_time_when_restarted() {
+        let mut clock = SceneClock::new();
+        clock.start();
+        std::thread::sleep(Duration::from_millis(2));
+        clock.stop();
+        let elapsed = clock.elapsed();
+
+        clock.start();
+        clock.start();
+
+        assert!(clock.elapsed() >= elapsed);
+    }
+
+    #[test]
+    fn should_reset_the_elapsed_time() {
+        let mut clock = SceneClock::new();
+        clock.start();
+        std::thread::sleep(Duration::from_millis(1));
+
+        clock.reset();
+
+        assert!(!clock.is_running());
+        assert_eq!(clock.elapsed(), Duration::ZERO);
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use crate::scenes::state::*;
+use crate::scenes::{Scene, SceneBox};
+
+/// Provides a way to create [`Scenes`](Scene) by name.
+///
+/// The registry maps names to factory functions.  When it is added as a resource, scenes are able
+/// to request a named scene with [`SceneChange::PushNamed`](crate::scenes::SceneChange), without
+/// having to know its concrete type.
+///
+/// # Examples
+///
+/// ```
+/// # use wolf_engine_core as wolf_engine;
+/// # use wolf_engine_framework::scenes::*;
+/// # use wolf_engine::Context;
+/// #
+/// # struct MainMenu;
+/// #
+/// # impl SceneTrait for MainMenu {
+/// #     fn update(&mut self, _context: &mut Context) -> Option<SceneChange> { None }
+/// #     fn render(&mut self, _context: &mut Context) {}
+/// # }
+/// #
+/// let mut registry = SceneRegistry::new();
+/// registry.register("main_menu", || Box::from(MainMenu));
+///
+/// let (_event_loop, context) = wolf_engine::init()
+///     .with_resource(registry)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Default)]
+pub struct SceneRegistry {
+    factories: HashMap<&'static str, Box<dyn Fn() -> SceneBox>>,
+}
+
+impl SceneRegistry {
+    /// Creates a new, empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a factory function under the provided name.
+    ///
+    /// Registering a name a second time replaces the previous factory.
+    pub fn register<F: Fn() -> SceneBox + 'static>(&mut self, name: &'static str, factory: F) {
+        self.factories.insert(name, Box::from(factory));
+    }
+
+    /// Returns `true` if a factory has been registered with the provided name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.factories.contains_key(name)
+    }
+
+    /// Creates a new, [`Unloaded`] [`Scene`] using the named factory, if it exists.
+    pub fn create(&self, name: &str) -> Option<Scene<Unloaded>> {
+        self.factories
+            .get(name)
+            .map(|factory| Scene::new_unloaded(factory()))
+    }
+}
+
+#[cfg(test)]
+mod scene_registry_tests {
+    use super::*;
+    use crate::scenes::MockSceneTrait;
+
+    #[test]
+    fn should_create_registered_scenes() {
+        let mut registry = SceneRegistry::new();
+        registry.register("test", || Box::from(MockSceneTrait::new()));
+
+        assert!(registry.contains("test"));
+        assert!(registry.create("test").is_some());
+        assert!(registry.create("missing").is_none());
+    }
+}
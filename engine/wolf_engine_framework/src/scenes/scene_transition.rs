@@ -0,0 +1,28 @@
+use std::time::Duration;
+
+use wolf_engine_core::Context;
+
+/// An alias for a [Boxed](Box) [`SceneTransition`].
+pub type SceneTransitionBox = Box<dyn SceneTransition>;
+
+/// Provides a visual effect, such as a fade, played before a scene change.
+///
+/// When a [`Stage`](crate::scenes::Stage) is asked to change scenes with a transition, it
+/// defers the change, and updates the transition once per [`Stage::update()`] instead.  The
+/// transition is rendered as an overlay on top of the current scenes.  Once the transition reports
+/// it has finished, the change is applied.
+#[allow(unused)]
+#[cfg_attr(test, mockall::automock)]
+pub trait SceneTransition {
+    /// Returns how long the transition takes.
+    fn duration(&self) -> Duration;
+
+    /// Advances the transition, and returns `true` once it has finished.
+    ///
+    /// `alpha` is how far along the transition is, from `0.0` when it starts, to `1.0` once its
+    /// [duration](SceneTransition::duration()) has elapsed.
+    fn update(&mut self, alpha: f32) -> bool;
+
+    /// Renders the transition on top of the current scenes.
+    fn render(&mut self, context: &mut Context);
+}
@@ -1,7 +1,12 @@
+use std::collections::VecDeque;
+use std::time::Instant;
+
+use wolf_engine_core::events::Event;
 use wolf_engine_core::Context;
 
+use crate::input::InputEvent;
 use crate::scenes::state::*;
-use crate::scenes::Scene;
+use crate::scenes::{IntoScene, LoadProgress, Scene, SceneRegistry, SceneTransitionBox};
 
 /// Represents an action command for the [`Stage`].
 pub enum SceneChange {
@@ -11,13 +16,135 @@ pub enum SceneChange {
     /// Combines a [`SceneChange::Clear`], and a [`SceneChange::Push`] into a single operation.
     CleanPush(Scene<Unloaded>),
 
+    /// Replace the top [`Scene`] with a new one, leaving the rest of the stack untouched.
+    ///
+    /// Unlike [`SceneChange::CleanPush`], only the top [`Scene`] is unloaded.  The
+    /// [`Scene`] underneath is neither paused, nor resumed.  Like a push, the new [`Scene`] is
+    /// loaded in the background, and the top [`Scene`] keeps running until the new one is done
+    /// loading.
+    Replace(Scene<Unloaded>),
+
+    /// Swap the top two [`Scenes`](Scene) on the stack, without loading, unloading, pausing, or
+    /// resuming either.
+    ///
+    /// Nothing happens if there are fewer than two [`Scenes`](Scene) on the stack.
+    Swap,
+
+    /// Push a new [`Scene`], created by name from the [`SceneRegistry`] resource.
+    ///
+    /// If there is no [`SceneRegistry`], or nothing is registered under the name, an error is
+    /// logged, and nothing happens.
+    PushNamed(&'static str),
+
     /// Pop a single [`Scene`] off the top of the stack.
     Pop,
 
+    /// Pop [`Scenes`](Scene) off the top of the stack until it has the given depth.
+    ///
+    /// See [`Stage::pop_to()`].
+    PopTo(usize),
+
     /// Pop all [`Scenes`](Scene) off the stack.
     Clear,
 }
 
+impl SceneChange {
+    /// Returns the [`SceneChangeKind`] of this change, without its payload.
+    pub fn kind(&self) -> SceneChangeKind {
+        match self {
+            Self::Push(_) => SceneChangeKind::Push,
+            Self::CleanPush(_) => SceneChangeKind::CleanPush,
+            Self::Replace(_) => SceneChangeKind::Replace,
+            Self::Swap => SceneChangeKind::Swap,
+            Self::PushNamed(_) => SceneChangeKind::PushNamed,
+            Self::Pop => SceneChangeKind::Pop,
+            Self::PopTo(_) => SceneChangeKind::PopTo,
+            Self::Clear => SceneChangeKind::Clear,
+        }
+    }
+}
+
+impl std::fmt::Debug for SceneChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Push(scene) | Self::CleanPush(scene) | Self::Replace(scene) => f
+                .debug_tuple(&format!("{:?}", self.kind()))
+                .field(&scene.name())
+                .finish(),
+            Self::PushNamed(name) => f.debug_tuple("PushNamed").field(name).finish(),
+            Self::PopTo(depth) => f.debug_tuple("PopTo").field(depth).finish(),
+            Self::Swap | Self::Pop | Self::Clear => write!(f, "{:?}", self.kind()),
+        }
+    }
+}
+
+/// Describes which [`SceneChange`] was made, without carrying any [`Scenes`](Scene).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SceneChangeKind {
+    Push,
+    CleanPush,
+    Replace,
+    Swap,
+    PushNamed,
+    Pop,
+    PopTo,
+    Clear,
+}
+
+/// A callback which is notified of every [`SceneChange`] applied by the [`Stage`].
+pub type SceneChangeObserver = Box<dyn FnMut(&SceneChangeKind)>;
+
+/// Provides a queue of [`SceneChanges`](SceneChange), making it possible for a [`Scene`] to
+/// request more than one change in a single update.
+///
+/// When this queue is stored as a resource, the [`Stage`] applies its changes, in the order they
+/// were pushed, right after the active [`Scene`] has been updated.  Changes returned from the
+/// update itself are applied first.
+///
+/// # Examples
+///
+/// ```
+/// # use wolf_engine_framework::scenes::*;
+/// # use wolf_engine_core::Context;
+/// #
+/// fn update(context: &mut Context) -> Option<SceneChange> {
+///     let mut scene_changes = context.resources().get_mut::<SceneChangeQueue>().unwrap();
+///     scene_changes.push(SceneChange::Pop);
+///     scene_changes.push(SceneChange::Pop);
+///     None
+/// }
+/// ```
+#[derive(Default)]
+pub struct SceneChangeQueue {
+    changes: VecDeque<SceneChange>,
+}
+
+impl SceneChangeQueue {
+    /// Creates a new, empty queue.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a [`SceneChange`] to the back of the queue.
+    pub fn push(&mut self, scene_change: SceneChange) {
+        self.changes.push_back(scene_change);
+    }
+
+    /// Returns the number of queued [`SceneChanges`](SceneChange).
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Returns `true` if there are no queued [`SceneChanges`](SceneChange).
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    fn take(&mut self) -> VecDeque<SceneChange> {
+        std::mem::take(&mut self.changes)
+    }
+}
+
 /// Provides a stack-like structure managing a collection of [`Scene`] objects.
 ///
 /// The Stage consists of a stack, on which the currently-loaded Scenes are stored.  Whatever
@@ -27,26 +154,113 @@ pub enum SceneChange {
 /// When a Scene is pushed to the stack, it is first [loaded](Scene::load()), and when a Scene is
 /// popped off the stack, it is [unloaded](Scene::unload()).
 ///
+/// When a Scene is pushed on top of the active Scene, the active Scene is
+/// [paused](Scene::pause()), and when the Scene above it is popped, it is
+/// [resumed](Scene::resume()).
+///
 /// All Scenes run with the Stage, even the "background" Scenes, but only the "active" Scene is
 /// able to return a [`SceneChange`] to control the Stage.  "Background" are only run through
 /// their "background" methods, which do not return a [`SceneChange`].
+///
+/// Scenes which take more than a single frame to load are kept aside, and
+/// [polled](Scene::poll_load()) once per update until they are done loading.  Meanwhile, the
+/// rest of the stack keeps running as normal.  Once a Scene is done loading, it is placed on top
+/// of the stack.
+///
+/// Each Scene's [`SceneClock`](crate::scenes::SceneClock) only runs while it is the active Scene.
+///
+/// Scene changes can also be played with a [`SceneTransition`](crate::scenes::SceneTransition),
+/// in which case the change is deferred until the transition has finished.
 #[derive(Default)]
 pub struct Stage {
     stack: Vec<Scene<Loaded>>,
+    loading: VecDeque<LoadingScene>,
+    transition: Option<PendingTransition>,
+    observer: Option<SceneChangeObserver>,
+}
+
+struct LoadingScene {
+    scene: Scene<Loading>,
+    replaces_active: bool,
+}
+
+struct PendingTransition {
+    effect: SceneTransitionBox,
+    started: Instant,
+    scene_change: SceneChange,
 }
 
 impl Stage {
     pub fn new() -> Self {
-        Self { stack: Vec::new() }
+        Self {
+            stack: Vec::new(),
+            loading: VecDeque::new(),
+            transition: None,
+            observer: None,
+        }
+    }
+
+    /// Sets a [`SceneChangeObserver`] to be notified whenever a [`SceneChange`] is applied.
+    ///
+    /// The observer only sees changes returned by, or queued from, the [`Scenes`](Scene).  When a
+    /// change is played with a transition, the observer is called once the change is actually
+    /// applied.  Any previously-set observer is replaced.
+    pub fn set_scene_change_observer(&mut self, observer: SceneChangeObserver) {
+        self.observer = Some(observer);
+    }
+
+    /// Returns the number of [`Scenes`](Scene) on the stack.
+    pub fn len(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Returns `true` if there are no [`Scenes`](Scene) on the stack.
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Returns `true` if there are [`Scenes`](Scene) waiting to finish loading.
+    pub fn is_loading(&self) -> bool {
+        !self.loading.is_empty()
+    }
+
+    /// Returns `true` if a [`SceneTransition`](crate::scenes::SceneTransition) is playing.
+    pub fn is_transitioning(&self) -> bool {
+        self.transition.is_some()
+    }
+
+    /// Returns the index of the active [`Scene`], or `None` if the stack is empty.
+    ///
+    /// The bottom of the stack is index `0`, so the active index is always `len() - 1`.
+    pub fn active_index(&self) -> Option<usize> {
+        self.stack.len().checked_sub(1)
+    }
+
+    /// Returns the [name](crate::scenes::SceneTrait::name()) of the active [`Scene`], or `None` if
+    /// the stack is empty.
+    pub fn active_name(&self) -> Option<&'static str> {
+        self.stack.last().map(|scene| scene.name())
+    }
+
+    /// Returns the [names](crate::scenes::SceneTrait::name()) of every [`Scene`] on the stack,
+    /// ordered from the bottom of the stack, to the top.
+    pub fn scene_names(&self) -> Vec<&'static str> {
+        self.stack.iter().map(|scene| scene.name()).collect()
     }
 
     /// Updates the whole [`Scene`] stack.
     ///
-    /// Updates are run from bottom-to-top order.  Only the top scene has its [`Scene::update()`]
-    /// method called, the rest get a [`Scene::background_update()`] instead.
+    /// Any [`Scene`] which is still loading is polled first.  Then, updates are run from
+    /// bottom-to-top order.  Only the top scene has its [`Scene::update()`] method called, the
+    /// rest get a [`Scene::background_update()`] instead, which is skipped for scenes whose
+    /// [background update interval](crate::scenes::SceneTrait::background_update_interval())
+    /// hasn't elapsed yet.
     pub fn update(&mut self, context: &mut Context) {
+        self.poll_loading_scenes(context);
+        self.run_transition(context);
         self.run_background_updates(context);
         self.run_active_update(context);
+        self.run_queued_scene_changes(context);
     }
 
     /// Renders the whole [`Scene`] stack.
@@ -58,25 +272,214 @@ impl Stage {
         if let Some(scene) = self.stack.last_mut() {
             scene.render(context);
         }
+        if let Some(transition) = self.transition.as_mut() {
+            transition.effect.render(context);
+        }
+    }
+
+    /// Sends an [`Event`] to every [`Scene`] on the stack, in bottom-to-top order.
+    ///
+    /// This is useful for notifications every scene cares about, such as a window resize.
+    pub fn broadcast_event(&mut self, context: &mut Context, event: &dyn Event) {
+        for scene in self.stack.iter_mut() {
+            scene.on_event(context, event);
+        }
+    }
+
+    /// Sends an [`InputEvent`] to the active [`Scene`], and applies any [`SceneChange`] it
+    /// returns.
+    ///
+    /// Background [`Scenes`](Scene) never receive input.
+    pub fn handle_input(&mut self, context: &mut Context, input: &InputEvent) {
+        if let Some(scene) = self.stack.last_mut() {
+            if let Some(scene_change) = scene.handle_input(context, input) {
+                self.apply_scene_change(context, scene_change);
+            }
+        }
     }
 
     /// Pushes a [`Scene`] to the top of the stack, and [loads](Scene::load()) it.
+    ///
+    /// The previously-active [`Scene`], if any, is [paused](Scene::pause()).
+    ///
+    /// If the [`Scene`] isn't done loading right away, it is kept aside, and polled during
+    /// [`Stage::update()`] until it is done, only then is it pushed to the stack.
     pub fn push(&mut self, context: &mut Context, scene: Scene<Unloaded>) {
-        let scene = scene.load(context);
+        self.start_loading(context, scene, false);
+    }
+
+    fn start_loading(
+        &mut self,
+        context: &mut Context,
+        scene: Scene<Unloaded>,
+        replaces_active: bool,
+    ) {
+        self.loading.push_back(LoadingScene {
+            scene: scene.start_loading(context),
+            replaces_active,
+        });
+        self.poll_loading_scenes(context);
+    }
+
+    /// Converts the value into a [`Scene`], then [pushes](Stage::push()) it.
+    ///
+    /// This makes it possible to push a plain [`SceneTrait`](crate::scenes::SceneTrait) object,
+    /// without boxing, and wrapping it by hand.
+    pub fn push_scene(&mut self, context: &mut Context, scene: impl IntoScene) {
+        self.push(context, scene.into_scene());
+    }
+
+    fn poll_loading_scenes(&mut self, context: &mut Context) {
+        while let Some(loading_scene) = self.loading.front_mut() {
+            match loading_scene.scene.poll_load(context) {
+                LoadProgress::Done => {
+                    let loading_scene = self.loading.pop_front().unwrap();
+                    let scene = loading_scene.scene.finish_loading();
+                    if loading_scene.replaces_active {
+                        self.replace_loaded(context, scene);
+                    } else {
+                        self.push_loaded(context, scene);
+                    }
+                }
+                LoadProgress::InProgress(_) => break,
+            }
+        }
+    }
+
+    fn push_loaded(&mut self, context: &mut Context, scene: Scene<Loaded>) {
+        if let Some(active_scene) = self.stack.last_mut() {
+            active_scene.pause(context);
+        }
+        self.push_active(scene);
+    }
+
+    fn push_active(&mut self, mut scene: Scene<Loaded>) {
+        scene.clock_mut().start();
         self.stack.push(scene);
     }
 
+    /// Plays the [`SceneTransition`](crate::scenes::SceneTransition), then pushes the [`Scene`]
+    /// once it has finished.
+    ///
+    /// If another transition is already playing, it is replaced, and its scene change is dropped.
+    pub fn push_with_transition(&mut self, scene: Scene<Unloaded>, transition: SceneTransitionBox) {
+        self.start_transition(SceneChange::Push(scene), transition);
+    }
+
+    /// Plays the [`SceneTransition`](crate::scenes::SceneTransition), then pops the active
+    /// [`Scene`] once it has finished.
+    ///
+    /// If another transition is already playing, it is replaced, and its scene change is dropped.
+    pub fn pop_with_transition(&mut self, transition: SceneTransitionBox) {
+        self.start_transition(SceneChange::Pop, transition);
+    }
+
+    fn start_transition(&mut self, scene_change: SceneChange, effect: SceneTransitionBox) {
+        self.transition = Some(PendingTransition {
+            effect,
+            started: Instant::now(),
+            scene_change,
+        });
+    }
+
+    fn run_transition(&mut self, context: &mut Context) {
+        let finished = match self.transition.as_mut() {
+            Some(transition) => {
+                let duration = transition.effect.duration().as_secs_f32();
+                let alpha = if duration > 0.0 {
+                    (transition.started.elapsed().as_secs_f32() / duration).min(1.0)
+                } else {
+                    1.0
+                };
+                transition.effect.update(alpha)
+            }
+            None => false,
+        };
+        if finished {
+            let transition = self.transition.take().unwrap();
+            self.apply_scene_change(context, transition.scene_change);
+        }
+    }
+
     /// Pops a [`Scene`] off the top of the stack, and [unloads](Scene::unload()) it.
+    ///
+    /// The newly-active [`Scene`], if any, is [resumed](Scene::resume()).
     pub fn pop(&mut self, context: &mut Context) {
         if let Some(scene) = self.stack.pop() {
             scene.unload(context);
+            if let Some(active_scene) = self.stack.last_mut() {
+                active_scene.resume(context);
+            }
+        }
+    }
+
+    /// Pops [`Scenes`](Scene) off the top of the stack, and [unloads](Scene::unload()) them, until
+    /// the stack has the given depth.
+    ///
+    /// Only the newly-active [`Scene`], if any, is [resumed](Scene::resume()), the ones popped along
+    /// the way are not.  Nothing happens if the stack isn't deeper than the given depth.
+    pub fn pop_to(&mut self, context: &mut Context, depth: usize) {
+        if depth >= self.stack.len() {
+            return;
+        }
+        while self.stack.len() > depth {
+            self.stack.pop().unwrap().unload(context);
+        }
+        if let Some(active_scene) = self.stack.last_mut() {
+            active_scene.resume(context);
         }
     }
 
+    /// Pops every [`Scene`] except for the bottom one.
+    ///
+    /// This is a shortcut for calling [`Stage::pop_to()`] with a depth of 1.
+    pub fn pop_to_root(&mut self, context: &mut Context) {
+        self.pop_to(context, 1);
+    }
+
     /// Pops all [`Scene`] objects from the stack, and [unloads](Scene::unload()) them.
+    ///
+    /// Any [`Scenes`](Scene) which are still loading are unloaded as well, and any playing
+    /// transition is cancelled.  None of the [`Scenes`](Scene) are resumed along the way.
     pub fn clear(&mut self, context: &mut Context) {
-        for _ in 0..self.stack.len() {
-            self.pop(context);
+        self.transition = None;
+        while let Some(loading_scene) = self.loading.pop_back() {
+            loading_scene.scene.unload(context);
+        }
+        while let Some(scene) = self.stack.pop() {
+            scene.unload(context);
+        }
+    }
+
+    fn replace(&mut self, context: &mut Context, scene: Scene<Unloaded>) {
+        self.start_loading(context, scene, true);
+    }
+
+    fn replace_loaded(&mut self, context: &mut Context, scene: Scene<Loaded>) {
+        if let Some(old_scene) = self.stack.pop() {
+            old_scene.unload(context);
+        }
+        self.push_active(scene);
+    }
+
+    fn push_named(&mut self, context: &mut Context, name: &str) {
+        let scene = context
+            .resources()
+            .get::<SceneRegistry>()
+            .ok()
+            .and_then(|registry| registry.create(name));
+        match scene {
+            Some(scene) => self.push(context, scene),
+            None => log::error!("There is no scene registered as \"{}\"", name),
+        }
+    }
+
+    fn swap(&mut self) {
+        let stack_size = self.stack.len();
+        if stack_size > 1 {
+            self.stack[stack_size - 1].clock_mut().stop();
+            self.stack.swap(stack_size - 1, stack_size - 2);
+            self.stack[stack_size - 1].clock_mut().start();
         }
     }
 
@@ -101,25 +504,55 @@ impl Stage {
     fn run_active_update(&mut self, context: &mut Context) {
         if let Some(scene) = self.stack.last_mut() {
             if let Some(scene_change) = scene.update(context) {
-                match scene_change {
-                    SceneChange::Push(new_scene) => self.push(context, new_scene),
-                    SceneChange::CleanPush(new_scene) => {
-                        self.clear(context);
-                        self.push(context, new_scene);
-                    }
-                    SceneChange::Pop => {
-                        self.pop(context);
-                    }
-                    SceneChange::Clear => self.clear(context),
-                }
+                self.apply_scene_change(context, scene_change);
+            }
+        }
+    }
+
+    fn run_queued_scene_changes(&mut self, context: &mut Context) {
+        let scene_changes = match context.resources().get_mut::<SceneChangeQueue>() {
+            Ok(mut scene_change_queue) => scene_change_queue.take(),
+            Err(_) => return,
+        };
+        for scene_change in scene_changes {
+            self.apply_scene_change(context, scene_change);
+        }
+    }
+
+    fn apply_scene_change(&mut self, context: &mut Context, scene_change: SceneChange) {
+        if let Some(observer) = self.observer.as_mut() {
+            observer(&scene_change.kind());
+        }
+        match scene_change {
+            SceneChange::Push(new_scene) => self.push(context, new_scene),
+            SceneChange::CleanPush(new_scene) => {
+                self.clear(context);
+                self.push(context, new_scene);
             }
+            SceneChange::Replace(new_scene) => self.replace(context, new_scene),
+            SceneChange::PushNamed(name) => self.push_named(context, name),
+            SceneChange::Swap => self.swap(),
+            SceneChange::Pop => {
+                self.pop(context);
+            }
+            SceneChange::PopTo(depth) => self.pop_to(context, depth),
+            SceneChange::Clear => self.clear(context),
         }
     }
 }
 
 #[cfg(test)]
 mod stage_tests {
-    use crate::scenes::MockSceneTrait;
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    use crate::input::Key;
+    use crate::scenes::{
+        EmptyScene, MockSceneTrait, MockSceneTransition, QuitScene, SceneClock, SceneTrait,
+    };
+
+    use test_case::test_case;
 
     use super::*;
 
@@ -129,6 +562,10 @@ mod stage_tests {
         let mut stage = Stage::new();
         let mut scene = MockSceneTrait::new();
         scene.expect_load().once().return_const(());
+        scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
         scene.expect_unload().once().return_const(());
         let scene = Scene::new_unloaded(Box::from(scene));
 
@@ -145,17 +582,32 @@ mod stage_tests {
 
         let mut background_scene = MockSceneTrait::new();
         background_scene.expect_load().once().return_const(());
+        background_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        background_scene
+            .expect_background_update_interval()
+            .return_const(None);
         background_scene
             .expect_background_update()
             .once()
             .return_const(());
+        background_scene
+            .expect_should_background_render()
+            .return_const(true);
         background_scene
             .expect_background_render()
             .once()
             .return_const(());
+        background_scene.expect_pause().once().return_const(());
         let background_scene = Scene::new_unloaded(Box::from(background_scene));
         let mut active_scene = MockSceneTrait::new();
         active_scene.expect_load().once().return_const(());
+        active_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
         active_scene.expect_update().once().returning(|_| None);
         active_scene.expect_render().once().return_const(());
         let active_scene = Scene::new_unloaded(Box::from(active_scene));
@@ -173,18 +625,30 @@ mod stage_tests {
 
         let mut new_scene = MockSceneTrait::new();
         new_scene.expect_load().once().return_const(());
+        new_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
         new_scene.expect_update().once().returning(|_| None);
         let new_scene = Scene::new_unloaded(Box::from(new_scene));
         let mut first_scene = MockSceneTrait::new();
         first_scene.expect_load().once().return_const(());
+        first_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
         first_scene
             .expect_update()
             .once()
             .return_once_st(|_| Some(SceneChange::Push(new_scene)));
+        first_scene
+            .expect_background_update_interval()
+            .return_const(None);
         first_scene
             .expect_background_update()
             .once()
             .return_const(());
+        first_scene.expect_pause().once().return_const(());
         let first_scene = Scene::new_unloaded(Box::from(first_scene));
         stage.push(&mut context, first_scene);
 
@@ -200,6 +664,10 @@ mod stage_tests {
 
         let mut scene = MockSceneTrait::new();
         scene.expect_load().once().return_const(());
+        scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
         scene
             .expect_update()
             .once()
@@ -218,10 +686,18 @@ mod stage_tests {
 
         let mut new_scene = MockSceneTrait::new();
         new_scene.expect_load().once().return_const(());
+        new_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
         new_scene.expect_update().once().returning(|_| None);
         let new_scene = Scene::new_unloaded(Box::from(new_scene));
         let mut first_scene = MockSceneTrait::new();
         first_scene.expect_load().once().return_const(());
+        first_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
         first_scene
             .expect_update()
             .once()
@@ -242,31 +718,124 @@ mod stage_tests {
     }
 
     #[test]
-    fn should_handle_clear_scene_change() {
+    fn should_handle_replace_scene_change() {
         let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
         let mut stage = Stage::new();
 
-        let mut second_scene = MockSceneTrait::new();
-        second_scene.expect_load().once().return_const(());
-        second_scene
+        let mut background_scene = MockSceneTrait::new();
+        background_scene.expect_load().once().return_const(());
+        background_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        background_scene
+            .expect_background_update_interval()
+            .return_const(None);
+        background_scene
+            .expect_background_update()
+            .times(2)
+            .return_const(());
+        background_scene.expect_unload().never();
+        background_scene.expect_pause().once().return_const(());
+        let background_scene = Scene::new_unloaded(Box::from(background_scene));
+        let mut new_scene = MockSceneTrait::new();
+        new_scene.expect_load().once().return_const(());
+        new_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        new_scene.expect_update().once().returning(|_| None);
+        let new_scene = Scene::new_unloaded(Box::from(new_scene));
+        let mut replaced_scene = MockSceneTrait::new();
+        replaced_scene.expect_load().once().return_const(());
+        replaced_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        replaced_scene
             .expect_update()
             .once()
-            .returning(|_| Some(SceneChange::Clear));
-        second_scene.expect_unload().once().return_const(());
-        let second_scene = Scene::new_unloaded(Box::from(second_scene));
+            .return_once_st(|_| Some(SceneChange::Replace(new_scene)));
+        replaced_scene.expect_unload().once().return_const(());
+        let replaced_scene = Scene::new_unloaded(Box::from(replaced_scene));
+        stage.push(&mut context, background_scene);
+        stage.push(&mut context, replaced_scene);
+
+        for _ in 0..2 {
+            stage.update(&mut context);
+        }
+
+        assert_eq!(
+            stage.stack.len(),
+            2,
+            "The background scene should still be on the stack."
+        )
+    }
+
+    #[test]
+    fn should_handle_swap_scene_change() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+
         let mut first_scene = MockSceneTrait::new();
         first_scene.expect_load().once().return_const(());
         first_scene
-            .expect_update()
+            .expect_poll_load()
             .once()
-            .return_once_st(|_| Some(SceneChange::Push(second_scene)));
+            .return_const(LoadProgress::Done);
+        first_scene
+            .expect_background_update_interval()
+            .return_const(None);
         first_scene
             .expect_background_update()
             .once()
             .return_const(());
-        first_scene.expect_unload().once().return_const(());
+        first_scene.expect_update().once().returning(|_| None);
+        first_scene.expect_pause().once().return_const(());
         let first_scene = Scene::new_unloaded(Box::from(first_scene));
+        let mut second_scene = MockSceneTrait::new();
+        second_scene.expect_load().once().return_const(());
+        second_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        second_scene
+            .expect_update()
+            .once()
+            .returning(|_| Some(SceneChange::Swap));
+        second_scene
+            .expect_background_update_interval()
+            .return_const(None);
+        second_scene
+            .expect_background_update()
+            .once()
+            .return_const(());
+        let second_scene = Scene::new_unloaded(Box::from(second_scene));
         stage.push(&mut context, first_scene);
+        stage.push(&mut context, second_scene);
+
+        for _ in 0..2 {
+            stage.update(&mut context);
+        }
+    }
+
+    #[test]
+    fn should_ignore_swap_scene_change_with_a_single_scene() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+
+        let mut scene = MockSceneTrait::new();
+        scene.expect_load().once().return_const(());
+        scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        scene
+            .expect_update()
+            .times(2)
+            .returning(|_| Some(SceneChange::Swap));
+        let scene = Scene::new_unloaded(Box::from(scene));
+        stage.push(&mut context, scene);
 
         for _ in 0..2 {
             stage.update(&mut context);
@@ -274,17 +843,861 @@ mod stage_tests {
 
         assert_eq!(
             stage.stack.len(),
-            0,
-            "There should be no scenes left on the stack."
-        )
+            1,
+            "The scene should still be on the stack."
+        );
     }
 
     #[test]
-    fn should_not_panic_on_empty_stack() {
-        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+    fn should_handle_push_named_scene_change() {
+        let mut registry = SceneRegistry::new();
+        registry.register("new_scene", || {
+            let mut new_scene = MockSceneTrait::new();
+            new_scene.expect_load().once().return_const(());
+            new_scene
+                .expect_poll_load()
+                .once()
+                .return_const(LoadProgress::Done);
+            new_scene.expect_update().once().returning(|_| None);
+            Box::from(new_scene)
+        });
+        let (_event_loop, mut context) = wolf_engine_core::init()
+            .with_resource(registry)
+            .build()
+            .unwrap();
         let mut stage = Stage::new();
+        let mut first_scene = MockSceneTrait::new();
+        first_scene.expect_load().once().return_const(());
+        first_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        first_scene
+            .expect_update()
+            .once()
+            .returning(|_| Some(SceneChange::PushNamed("new_scene")));
+        first_scene
+            .expect_background_update_interval()
+            .return_const(None);
+        first_scene
+            .expect_background_update()
+            .once()
+            .return_const(());
+        first_scene.expect_pause().once().return_const(());
+        stage.push(&mut context, Scene::new_unloaded(Box::from(first_scene)));
 
-        stage.update(&mut context);
+        for _ in 0..2 {
+            stage.update(&mut context);
+        }
+
+        assert_eq!(stage.len(), 2, "The named scene should have been pushed.");
+    }
+
+    #[test]
+    fn should_ignore_push_named_scene_change_for_unknown_names() {
+        let (_event_loop, mut context) = wolf_engine_core::init()
+            .with_resource(SceneRegistry::new())
+            .build()
+            .unwrap();
+        let mut stage = Stage::new();
+        let mut scene = MockSceneTrait::new();
+        scene.expect_load().once().return_const(());
+        scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        scene
+            .expect_update()
+            .once()
+            .returning(|_| Some(SceneChange::PushNamed("missing")));
+        stage.push(&mut context, Scene::new_unloaded(Box::from(scene)));
+
+        stage.update(&mut context);
+
+        assert_eq!(stage.len(), 1, "No scene should have been pushed.");
+    }
+
+    #[test]
+    fn should_handle_clear_scene_change() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+
+        let mut second_scene = MockSceneTrait::new();
+        second_scene.expect_load().once().return_const(());
+        second_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        second_scene
+            .expect_update()
+            .once()
+            .returning(|_| Some(SceneChange::Clear));
+        second_scene.expect_unload().once().return_const(());
+        let second_scene = Scene::new_unloaded(Box::from(second_scene));
+        let mut first_scene = MockSceneTrait::new();
+        first_scene.expect_load().once().return_const(());
+        first_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        first_scene
+            .expect_update()
+            .once()
+            .return_once_st(|_| Some(SceneChange::Push(second_scene)));
+        first_scene
+            .expect_background_update_interval()
+            .return_const(None);
+        first_scene
+            .expect_background_update()
+            .once()
+            .return_const(());
+        first_scene.expect_pause().once().return_const(());
+        first_scene.expect_unload().once().return_const(());
+        let first_scene = Scene::new_unloaded(Box::from(first_scene));
+        stage.push(&mut context, first_scene);
+
+        for _ in 0..2 {
+            stage.update(&mut context);
+        }
+
+        assert_eq!(
+            stage.stack.len(),
+            0,
+            "There should be no scenes left on the stack."
+        )
+    }
+
+    #[test]
+    fn should_pause_scene_when_it_is_deactivated() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+
+        let mut first_scene = MockSceneTrait::new();
+        first_scene.expect_load().once().return_const(());
+        first_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        first_scene.expect_pause().once().return_const(());
+        first_scene.expect_resume().never();
+        let first_scene = Scene::new_unloaded(Box::from(first_scene));
+        let mut second_scene = MockSceneTrait::new();
+        second_scene.expect_load().once().return_const(());
+        second_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        second_scene.expect_pause().never();
+        let second_scene = Scene::new_unloaded(Box::from(second_scene));
+
+        stage.push(&mut context, first_scene);
+        stage.push(&mut context, second_scene);
+    }
+
+    #[test]
+    fn should_resume_scene_when_it_is_reactivated() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+
+        let mut first_scene = MockSceneTrait::new();
+        first_scene.expect_load().once().return_const(());
+        first_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        first_scene.expect_pause().once().return_const(());
+        first_scene.expect_resume().once().return_const(());
+        let first_scene = Scene::new_unloaded(Box::from(first_scene));
+        let mut second_scene = MockSceneTrait::new();
+        second_scene.expect_load().once().return_const(());
+        second_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        second_scene.expect_unload().once().return_const(());
+        let second_scene = Scene::new_unloaded(Box::from(second_scene));
+
+        stage.push(&mut context, first_scene);
+        stage.push(&mut context, second_scene);
+        stage.pop(&mut context);
+    }
+
+    #[test]
+    fn should_report_empty_stack_size() {
+        let stage = Stage::new();
+
+        assert_eq!(stage.len(), 0);
+        assert!(stage.is_empty());
+        assert_eq!(stage.active_index(), None);
+    }
+
+    #[test]
+    fn should_report_single_scene_stack_size() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+        let mut scene = MockSceneTrait::new();
+        scene.expect_load().once().return_const(());
+        scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+
+        stage.push(&mut context, Scene::new_unloaded(Box::from(scene)));
+
+        assert_eq!(stage.len(), 1);
+        assert!(!stage.is_empty());
+        assert_eq!(stage.active_index(), Some(0));
+    }
+
+    #[test]
+    fn should_report_multi_scene_stack_size() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+        for i in 0..3 {
+            let mut scene = MockSceneTrait::new();
+            scene.expect_load().once().return_const(());
+            scene
+                .expect_poll_load()
+                .once()
+                .return_const(LoadProgress::Done);
+            scene
+                .expect_pause()
+                .times(if i < 2 { 1 } else { 0 })
+                .return_const(());
+            stage.push(&mut context, Scene::new_unloaded(Box::from(scene)));
+        }
+
+        assert_eq!(stage.len(), 3);
+        assert!(!stage.is_empty());
+        assert_eq!(stage.active_index(), Some(2));
+    }
+
+    #[derive(Event, Debug)]
+    struct TestEvent;
+
+    #[test]
+    fn should_broadcast_events_to_all_scenes() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+        for i in 0..3 {
+            let mut scene = MockSceneTrait::new();
+            scene.expect_load().once().return_const(());
+            scene
+                .expect_poll_load()
+                .once()
+                .return_const(LoadProgress::Done);
+            scene
+                .expect_pause()
+                .times(if i < 2 { 1 } else { 0 })
+                .return_const(());
+            scene
+                .expect_on_event()
+                .withf(|_, event| event.downcast_ref::<TestEvent>().is_some())
+                .once()
+                .return_const(());
+            stage.push(&mut context, Scene::new_unloaded(Box::from(scene)));
+        }
+
+        stage.broadcast_event(&mut context, &TestEvent);
+    }
+
+    #[test]
+    fn should_poll_loading_scenes_until_they_are_done() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+
+        let mut first_scene = MockSceneTrait::new();
+        first_scene.expect_load().once().return_const(());
+        first_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        first_scene.expect_update().once().returning(|_| None);
+        first_scene.expect_pause().once().return_const(());
+        first_scene
+            .expect_background_update_interval()
+            .return_const(None);
+        first_scene
+            .expect_background_update()
+            .once()
+            .return_const(());
+        let first_scene = Scene::new_unloaded(Box::from(first_scene));
+        let mut loading_scene = MockSceneTrait::new();
+        loading_scene.expect_load().once().return_const(());
+        let mut polls = 0;
+        loading_scene
+            .expect_poll_load()
+            .times(3)
+            .returning(move |_| {
+                polls += 1;
+                if polls < 3 {
+                    LoadProgress::InProgress(polls as f32 / 3.0)
+                } else {
+                    LoadProgress::Done
+                }
+            });
+        loading_scene.expect_update().once().returning(|_| None);
+        let loading_scene = Scene::new_unloaded(Box::from(loading_scene));
+        stage.push(&mut context, first_scene);
+
+        stage.push(&mut context, loading_scene);
+        assert!(stage.is_loading(), "The scene should still be loading.");
+        assert_eq!(
+            stage.len(),
+            1,
+            "The loading scene should not be active yet."
+        );
+        stage.update(&mut context);
+        assert!(stage.is_loading(), "The scene should still be loading.");
+        stage.update(&mut context);
+
+        assert!(!stage.is_loading(), "The scene should be done loading.");
+        assert_eq!(stage.len(), 2, "The loaded scene should be active.");
+    }
+
+    #[test]
+    fn should_unload_loading_scenes_on_clear() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+        let mut scene = MockSceneTrait::new();
+        scene.expect_load().once().return_const(());
+        scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::InProgress(0.0));
+        scene.expect_unload().once().return_const(());
+
+        stage.push(&mut context, Scene::new_unloaded(Box::from(scene)));
+        stage.clear(&mut context);
+
+        assert!(!stage.is_loading(), "The loading scene should be gone.");
+    }
+
+    #[test]
+    fn should_not_panic_on_empty_stack() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+
+        stage.update(&mut context);
+        stage.render(&mut context);
+    }
+
+    struct CountingScene {
+        updates: Rc<Cell<usize>>,
+        background_update_interval: Option<Duration>,
+    }
+
+    impl SceneTrait for CountingScene {
+        fn update(&mut self, _context: &mut Context) -> Option<SceneChange> {
+            self.updates.set(self.updates.get() + 1);
+            None
+        }
+
+        fn render(&mut self, _context: &mut Context) {}
+
+        fn background_update(&mut self, _context: &mut Context) {
+            self.updates.set(self.updates.get() + 1);
+        }
+
+        fn background_update_interval(&self) -> Option<Duration> {
+            self.background_update_interval
+        }
+    }
+
+    #[test]
+    fn should_throttle_background_updates() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+        let background_updates = Rc::new(Cell::new(0));
+        let active_updates = Rc::new(Cell::new(0));
+        stage.push(
+            &mut context,
+            Scene::new_unloaded(Box::from(CountingScene {
+                updates: background_updates.clone(),
+                background_update_interval: Some(Duration::from_secs(3600)),
+            })),
+        );
+        stage.push(
+            &mut context,
+            Scene::new_unloaded(Box::from(CountingScene {
+                updates: active_updates.clone(),
+                background_update_interval: None,
+            })),
+        );
+
+        for _ in 0..10 {
+            stage.update(&mut context);
+        }
+
+        assert_eq!(
+            background_updates.get(),
+            1,
+            "Only the first background update should have run"
+        );
+        assert_eq!(active_updates.get(), 10);
+    }
+
+    #[test]
+    fn should_apply_queued_scene_changes_in_order() {
+        let (_event_loop, mut context) = wolf_engine_core::init()
+            .with_resource(SceneChangeQueue::new())
+            .build()
+            .unwrap();
+        let mut stage = Stage::new();
+
+        let mut new_scene = MockSceneTrait::new();
+        new_scene.expect_load().once().return_const(());
+        new_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        let mut new_scene = Some(new_scene);
+        let mut first_scene = MockSceneTrait::new();
+        first_scene.expect_load().once().return_const(());
+        first_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        first_scene
+            .expect_update()
+            .once()
+            .returning(move |context| {
+                let mut scene_changes = context.resources().get_mut::<SceneChangeQueue>().unwrap();
+                scene_changes.push(SceneChange::Pop);
+                scene_changes.push(SceneChange::Push(Scene::new_unloaded(Box::from(
+                    new_scene.take().unwrap(),
+                ))));
+                None
+            });
+        first_scene.expect_unload().once().return_const(());
+
+        stage.push(&mut context, Scene::new_unloaded(Box::from(first_scene)));
+        stage.update(&mut context);
+
+        assert_eq!(stage.len(), 1);
+        assert!(context
+            .resources()
+            .get::<SceneChangeQueue>()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn should_only_send_input_to_the_active_scene() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+        let mut background_scene = MockSceneTrait::new();
+        background_scene.expect_load().once().return_const(());
+        background_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        background_scene.expect_pause().once().return_const(());
+        background_scene.expect_handle_input().never();
+        let mut active_scene = MockSceneTrait::new();
+        active_scene.expect_load().once().return_const(());
+        active_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        active_scene
+            .expect_handle_input()
+            .once()
+            .returning(|_, _| None);
+
+        stage.push(
+            &mut context,
+            Scene::new_unloaded(Box::from(background_scene)),
+        );
+        stage.push(&mut context, Scene::new_unloaded(Box::from(active_scene)));
+        stage.handle_input(&mut context, &InputEvent::KeyDown(Key::Space));
+    }
+
+    #[test]
+    fn should_apply_scene_changes_returned_from_input() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+        let mut scene = MockSceneTrait::new();
+        scene.expect_load().once().return_const(());
+        scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        scene
+            .expect_handle_input()
+            .once()
+            .returning(|_, _| Some(SceneChange::Pop));
+        scene.expect_unload().once().return_const(());
+
+        stage.push(&mut context, Scene::new_unloaded(Box::from(scene)));
+        stage.handle_input(&mut context, &InputEvent::KeyDown(Key::Escape));
+
+        assert!(stage.is_empty());
+    }
+
+    #[test]
+    fn should_defer_scene_changes_until_the_transition_finishes() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+        let mut scene = MockSceneTrait::new();
+        scene.expect_load().once().return_const(());
+        scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        scene.expect_update().once().returning(|_| None);
+        scene.expect_render().once().return_const(());
+        scene.expect_unload().once().return_const(());
+        stage.push(&mut context, Scene::new_unloaded(Box::from(scene)));
+        let mut transition = MockSceneTransition::new();
+        transition.expect_duration().return_const(Duration::ZERO);
+        let mut updates = 0;
+        transition.expect_update().times(2).returning(move |alpha| {
+            assert_eq!(alpha, 1.0);
+            updates += 1;
+            updates == 2
+        });
+        transition.expect_render().once().return_const(());
+
+        stage.pop_with_transition(Box::from(transition));
+        stage.update(&mut context);
         stage.render(&mut context);
+        assert_eq!(stage.len(), 1, "The pop should wait for the transition");
+        assert!(stage.is_transitioning());
+
+        stage.update(&mut context);
+        assert!(stage.is_empty());
+        assert!(!stage.is_transitioning());
+        stage.render(&mut context);
+    }
+
+    struct ClockScene {
+        elapsed: Rc<Cell<Duration>>,
+    }
+
+    impl ClockScene {
+        fn record_clock(&self, context: &mut Context) {
+            let clock = context.resources().get::<SceneClock>().unwrap();
+            self.elapsed.set(clock.elapsed());
+        }
+    }
+
+    impl SceneTrait for ClockScene {
+        fn update(&mut self, context: &mut Context) -> Option<SceneChange> {
+            self.record_clock(context);
+            None
+        }
+
+        fn render(&mut self, _context: &mut Context) {}
+
+        fn background_update(&mut self, context: &mut Context) {
+            self.record_clock(context);
+        }
+    }
+
+    #[test]
+    fn should_only_run_the_active_scenes_clock() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+        let background_elapsed = Rc::new(Cell::new(Duration::ZERO));
+        let active_elapsed = Rc::new(Cell::new(Duration::ZERO));
+        stage.push(
+            &mut context,
+            Scene::new_unloaded(Box::from(ClockScene {
+                elapsed: background_elapsed.clone(),
+            })),
+        );
+        std::thread::sleep(Duration::from_millis(2));
+        stage.push(
+            &mut context,
+            Scene::new_unloaded(Box::from(ClockScene {
+                elapsed: active_elapsed.clone(),
+            })),
+        );
+
+        stage.update(&mut context);
+        let (background_before, active_before) = (background_elapsed.get(), active_elapsed.get());
+        std::thread::sleep(Duration::from_millis(5));
+        stage.update(&mut context);
+
+        assert!(background_before >= Duration::from_millis(2));
+        assert_eq!(background_elapsed.get(), background_before);
+        assert!(active_elapsed.get() >= active_before + Duration::from_millis(5));
+    }
+
+    #[test]
+    fn should_resume_the_clock_of_the_newly_active_scene() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+        let elapsed = Rc::new(Cell::new(Duration::ZERO));
+        stage.push(
+            &mut context,
+            Scene::new_unloaded(Box::from(ClockScene {
+                elapsed: elapsed.clone(),
+            })),
+        );
+        stage.push(&mut context, Scene::new_unloaded(Box::from(EmptyScene)));
+        stage.update(&mut context);
+        let paused_elapsed = elapsed.get();
+
+        stage.pop(&mut context);
+        std::thread::sleep(Duration::from_millis(2));
+        stage.update(&mut context);
+
+        assert!(elapsed.get() >= paused_elapsed + Duration::from_millis(2));
+    }
+
+    #[test]
+    fn should_push_plain_scene_trait_objects() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+
+        stage.push_scene(&mut context, EmptyScene);
+        stage.push_scene(&mut context, Scene::new_unloaded(Box::from(EmptyScene)));
+
+        assert_eq!(stage.len(), 2);
+    }
+
+    #[test]
+    fn should_skip_background_renders_when_the_scene_opts_out() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+        let mut background_scene = MockSceneTrait::new();
+        background_scene.expect_load().once().return_const(());
+        background_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        background_scene.expect_pause().once().return_const(());
+        background_scene
+            .expect_background_update_interval()
+            .return_const(None);
+        background_scene
+            .expect_background_update()
+            .times(2)
+            .return_const(());
+        background_scene
+            .expect_should_background_render()
+            .return_const(false);
+        background_scene.expect_background_render().never();
+
+        stage.push(
+            &mut context,
+            Scene::new_unloaded(Box::from(background_scene)),
+        );
+        stage.push(&mut context, Scene::new_unloaded(Box::from(EmptyScene)));
+        for _ in 0..2 {
+            stage.update(&mut context);
+            stage.render(&mut context);
+        }
+    }
+
+    #[test]
+    fn should_keep_the_replaced_scene_until_the_new_scene_is_done_loading() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+        let mut new_scene = MockSceneTrait::new();
+        new_scene.expect_load().once().return_const(());
+        let mut polls = 0;
+        new_scene.expect_poll_load().times(3).returning(move |_| {
+            polls += 1;
+            if polls < 3 {
+                LoadProgress::InProgress(polls as f32 / 3.0)
+            } else {
+                LoadProgress::Done
+            }
+        });
+        new_scene.expect_update().once().returning(|_| None);
+        let new_scene = Scene::new_unloaded(Box::from(new_scene));
+        let mut replaced_scene = MockSceneTrait::new();
+        replaced_scene.expect_load().once().return_const(());
+        replaced_scene
+            .expect_poll_load()
+            .once()
+            .return_const(LoadProgress::Done);
+        let mut new_scene = Some(new_scene);
+        replaced_scene
+            .expect_update()
+            .times(2)
+            .returning_st(move |_| new_scene.take().map(SceneChange::Replace));
+        replaced_scene.expect_unload().once().return_const(());
+        stage.push(&mut context, Scene::new_unloaded(Box::from(replaced_scene)));
+
+        for _ in 0..2 {
+            stage.update(&mut context);
+            assert!(stage.is_loading(), "The new scene should still be loading.");
+            assert_eq!(stage.len(), 1);
+        }
+        stage.update(&mut context);
+
+        assert!(!stage.is_loading(), "The new scene should be done loading.");
+        assert_eq!(stage.len(), 1, "Only the new scene should be on the stack.");
+    }
+
+    struct LifecycleScene {
+        unloads: Rc<Cell<usize>>,
+        resumes: Rc<Cell<usize>>,
+    }
+
+    impl SceneTrait for LifecycleScene {
+        fn update(&mut self, _context: &mut Context) -> Option<SceneChange> {
+            None
+        }
+
+        fn render(&mut self, _context: &mut Context) {}
+
+        fn unload(&mut self, _context: &mut Context) {
+            self.unloads.set(self.unloads.get() + 1);
+        }
+
+        fn resume(&mut self, _context: &mut Context) {
+            self.resumes.set(self.resumes.get() + 1);
+        }
+    }
+
+    fn lifecycle_stage(
+        context: &mut Context,
+        depth: usize,
+    ) -> (Stage, Rc<Cell<usize>>, Rc<Cell<usize>>) {
+        let mut stage = Stage::new();
+        let unloads = Rc::new(Cell::new(0));
+        let resumes = Rc::new(Cell::new(0));
+        for _ in 0..depth {
+            stage.push_scene(
+                context,
+                LifecycleScene {
+                    unloads: unloads.clone(),
+                    resumes: resumes.clone(),
+                },
+            );
+        }
+        (stage, unloads, resumes)
+    }
+
+    #[test]
+    fn should_pop_to_the_root_scene() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let (mut stage, unloads, resumes) = lifecycle_stage(&mut context, 4);
+
+        stage.pop_to_root(&mut context);
+
+        assert_eq!(stage.len(), 1);
+        assert_eq!(unloads.get(), 3);
+        assert_eq!(resumes.get(), 1, "Only the root scene should be resumed");
+    }
+
+    #[test]
+    fn should_handle_pop_to_scene_change() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let (mut stage, unloads, _resumes) = lifecycle_stage(&mut context, 4);
+
+        stage.apply_scene_change(&mut context, SceneChange::PopTo(2));
+
+        assert_eq!(stage.len(), 2);
+        assert_eq!(unloads.get(), 2);
+    }
+
+    #[test]
+    fn should_not_pop_to_a_depth_larger_than_the_stack() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let (mut stage, unloads, resumes) = lifecycle_stage(&mut context, 2);
+
+        stage.pop_to(&mut context, 5);
+        stage.pop_to(&mut context, 2);
+
+        assert_eq!(stage.len(), 2);
+        assert_eq!(unloads.get(), 0);
+        assert_eq!(resumes.get(), 0);
+    }
+
+    #[test]
+    fn should_report_the_active_scene_name() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+        assert_eq!(stage.active_name(), None);
+
+        stage.push_scene(&mut context, EmptyScene);
+        assert_eq!(
+            stage.active_name(),
+            Some(std::any::type_name::<EmptyScene>())
+        );
+        stage.push_scene(&mut context, QuitScene);
+
+        assert_eq!(
+            stage.active_name(),
+            Some(std::any::type_name::<QuitScene>())
+        );
+    }
+
+    #[test]
+    fn should_list_scene_names_from_bottom_to_top() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let mut stage = Stage::new();
+        assert!(stage.scene_names().is_empty());
+
+        stage.push_scene(&mut context, EmptyScene);
+        stage.push_scene(&mut context, QuitScene);
+        stage.push_scene(&mut context, EmptyScene);
+
+        assert_eq!(
+            stage.scene_names(),
+            vec![
+                std::any::type_name::<EmptyScene>(),
+                std::any::type_name::<QuitScene>(),
+                std::any::type_name::<EmptyScene>(),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_notify_the_observer_of_scene_changes_in_order() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let kinds = Rc::new(RefCell::new(Vec::new()));
+        let mut stage = Stage::new();
+        let observed_kinds = kinds.clone();
+        stage.set_scene_change_observer(Box::new(move |kind| {
+            observed_kinds.borrow_mut().push(*kind)
+        }));
+
+        stage.apply_scene_change(&mut context, SceneChange::Push(EmptyScene.into_scene()));
+        stage.apply_scene_change(&mut context, SceneChange::Push(EmptyScene.into_scene()));
+        stage.apply_scene_change(&mut context, SceneChange::Swap);
+        stage.apply_scene_change(&mut context, SceneChange::Pop);
+        stage.apply_scene_change(&mut context, SceneChange::Clear);
+
+        assert_eq!(
+            *kinds.borrow(),
+            vec![
+                SceneChangeKind::Push,
+                SceneChangeKind::Push,
+                SceneChangeKind::Swap,
+                SceneChangeKind::Pop,
+                SceneChangeKind::Clear,
+            ]
+        );
+    }
+
+    #[test_case(SceneChange::Push(EmptyScene.into_scene()), SceneChangeKind::Push)]
+    #[test_case(SceneChange::CleanPush(EmptyScene.into_scene()), SceneChangeKind::CleanPush)]
+    #[test_case(SceneChange::Replace(EmptyScene.into_scene()), SceneChangeKind::Replace)]
+    #[test_case(SceneChange::Swap, SceneChangeKind::Swap)]
+    #[test_case(SceneChange::PushNamed("menu"), SceneChangeKind::PushNamed)]
+    #[test_case(SceneChange::Pop, SceneChangeKind::Pop)]
+    #[test_case(SceneChange::PopTo(1), SceneChangeKind::PopTo)]
+    #[test_case(SceneChange::Clear, SceneChangeKind::Clear)]
+    fn should_map_scene_changes_to_their_kind(scene_change: SceneChange, kind: SceneChangeKind) {
+        assert_eq!(scene_change.kind(), kind);
+    }
+
+    #[test]
+    fn should_debug_format_scene_changes_without_their_scenes() {
+        let push = SceneChange::Push(EmptyScene.into_scene());
+
+        assert_eq!(
+            format!("{:?}", push),
+            format!("Push({:?})", std::any::type_name::<EmptyScene>())
+        );
+        assert_eq!(format!("{:?}", SceneChange::PopTo(2)), "PopTo(2)");
+        assert_eq!(format!("{:?}", SceneChange::Pop), "Pop");
     }
 }
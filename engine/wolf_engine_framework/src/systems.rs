@@ -0,0 +1,200 @@
+//! Provides a lightweight way to split game logic into ordered systems.
+
+use wolf_engine_core::Context;
+
+/// An alias for a [Boxed](Box) system function.
+pub type SystemBox = Box<dyn FnMut(&mut Context)>;
+
+/// Provides an ordered list of labeled systems, which are run one after the other.
+///
+/// Instead of putting all game logic into a single, monolithic
+/// [`SceneTrait::update()`](crate::scenes::SceneTrait::update()), a scene can own a schedule, and
+/// [run](SystemSchedule::run()) it from its update.  Systems are run in the order they were added,
+/// unless they were placed [before](SystemSchedule::add_before()), or
+/// [after](SystemSchedule::add_after()) another system.
+///
+/// # Examples
+///
+/// ```
+/// # use wolf_engine_framework::systems::SystemSchedule;
+/// #
+/// let mut schedule = SystemSchedule::new();
+/// schedule
+///     .add("physics", |_context| { /* Move things around. */ })
+///     .add("render_prep", |_context| { /* Get ready to draw. */ })
+///     .add_before("physics", "input", |_context| { /* Read the controls. */ });
+///
+/// assert_eq!(schedule.labels().collect::<Vec<_>>(), ["input", "physics", "render_prep"]);
+/// ```
+#[derive(Default)]
+pub struct SystemSchedule {
+    systems: Vec<(String, SystemBox)>,
+}
+
+impl SystemSchedule {
+    /// Creates a new, empty schedule.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a system to the end of the schedule.
+    pub fn add<F>(&mut self, label: &str, system: F) -> &mut Self
+    where
+        F: FnMut(&mut Context) + 'static,
+    {
+        self.systems.push((label.to_string(), Box::from(system)));
+        self
+    }
+
+    /// Adds a system, so it runs right before the system labeled `before`.
+    ///
+    /// If there is no system labeled `before`, a warning is logged, and the system is added to the
+    /// end of the schedule instead.
+    pub fn add_before<F>(&mut self, before: &str, label: &str, system: F) -> &mut Self
+    where
+        F: FnMut(&mut Context) + 'static,
+    {
+        match self.position(before) {
+            Some(index) => self.insert(index, label, system),
+            None => self.add_missing(before, label, system),
+        }
+    }
+
+    /// Adds a system, so it runs right after the system labeled `after`.
+    ///
+    /// If there is no system labeled `after`, a warning is logged, and the system is added to the
+    /// end of the schedule instead.
+    pub fn add_after<F>(&mut self, after: &str, label: &str, system: F) -> &mut Self
+    where
+        F: FnMut(&mut Context) + 'static,
+    {
+        match self.position(after) {
+            Some(index) => self.insert(index + 1, label, system),
+            None => self.add_missing(after, label, system),
+        }
+    }
+
+    /// Removes the system with the provided label.
+    ///
+    /// Returns `true` if a system was removed.
+    pub fn remove(&mut self, label: &str) -> bool {
+        self.position(label)
+            .map(|index| self.systems.remove(index))
+            .is_some()
+    }
+
+    /// Runs every system, in order.
+    pub fn run(&mut self, context: &mut Context) {
+        for (_, system) in self.systems.iter_mut() {
+            system(context);
+        }
+    }
+
+    /// Returns the labels of every system, in the order they run.
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.systems.iter().map(|(label, _)| label.as_str())
+    }
+
+    /// Returns the number of systems in the schedule.
+    pub fn len(&self) -> usize {
+        self.systems.len()
+    }
+
+    /// Returns `true` if there are no systems in the schedule.
+    pub fn is_empty(&self) -> bool {
+        self.systems.is_empty()
+    }
+
+    fn position(&self, label: &str) -> Option<usize> {
+        self.systems
+            .iter()
+            .position(|(system_label, _)| system_label == label)
+    }
+
+    fn insert<F>(&mut self, index: usize, label: &str, system: F) -> &mut Self
+    where
+        F: FnMut(&mut Context) + 'static,
+    {
+        self.systems
+            .insert(index, (label.to_string(), Box::from(system)));
+        self
+    }
+
+    fn add_missing<F>(&mut self, missing: &str, label: &str, system: F) -> &mut Self
+    where
+        F: FnMut(&mut Context) + 'static,
+    {
+        log::warn!(
+            "There is no system labeled \"{}\", so \"{}\" was added to the end of the schedule",
+            missing,
+            label
+        );
+        self.add(label, system)
+    }
+}
+
+#[cfg(test)]
+mod system_schedule_tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    fn recording_system(
+        log: &Rc<RefCell<Vec<&'static str>>>,
+        label: &'static str,
+    ) -> impl FnMut(&mut Context) + 'static {
+        let log = log.clone();
+        move |_| log.borrow_mut().push(label)
+    }
+
+    #[test]
+    fn should_run_systems_in_declared_order() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut schedule = SystemSchedule::new();
+        schedule
+            .add("a", recording_system(&log, "a"))
+            .add("b", recording_system(&log, "b"))
+            .add("c", recording_system(&log, "c"));
+
+        schedule.run(&mut context);
+        schedule.run(&mut context);
+
+        assert_eq!(*log.borrow(), ["a", "b", "c", "a", "b", "c"]);
+    }
+
+    #[test]
+    fn should_insert_systems_before_and_after_others() {
+        let (_event_loop, mut context) = wolf_engine_core::init().build().unwrap();
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut schedule = SystemSchedule::new();
+        schedule
+            .add("physics", recording_system(&log, "physics"))
+            .add("render", recording_system(&log, "render"))
+            .add_before("physics", "input", recording_system(&log, "input"))
+            .add_after(
+                "physics",
+                "collisions",
+                recording_system(&log, "collisions"),
+            )
+            .add_after("missing", "audio", recording_system(&log, "audio"));
+
+        schedule.run(&mut context);
+
+        assert_eq!(
+            *log.borrow(),
+            ["input", "physics", "collisions", "render", "audio"]
+        );
+    }
+
+    #[test]
+    fn should_remove_systems() {
+        let mut schedule = SystemSchedule::new();
+        schedule.add("a", |_| ()).add("b", |_| ());
+
+        assert!(schedule.remove("a"));
+        assert!(!schedule.remove("a"));
+        assert_eq!(schedule.labels().collect::<Vec<_>>(), ["b"]);
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Represents the ways a window can restrict the cursor's movement.
+#[derive(Default, Debug, Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CursorGrabMode {
+    /// The cursor moves freely. (Default)
+    #[default]
+    None,
+
+    /// The cursor is kept inside the window.
+    Confined,
+
+    /// The cursor is kept in place, only reporting relative movement.
+    Locked,
+}
+
+impl CursorGrabMode {
+    /// Returns the closest mode a back-end is able to support.
+    ///
+    /// Back-ends without support for locking the cursor fall back to
+    /// [`CursorGrabMode::Confined`] instead of [`CursorGrabMode::Locked`].
+    pub fn supported(self, supports_locking: bool) -> Self {
+        match self {
+            Self::Locked if !supports_locking => Self::Confined,
+            mode => mode,
+        }
+    }
+}
+
+#[cfg(test)]
+mod cursor_grab_mode_tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(CursorGrabMode::None, true, CursorGrabMode::None)]
+    #[test_case(CursorGrabMode::Confined, true, CursorGrabMode::Confined)]
+    #[test_case(CursorGrabMode::Locked, true, CursorGrabMode::Locked)]
+    #[test_case(CursorGrabMode::None, false, CursorGrabMode::None)]
+    #[test_case(CursorGrabMode::Confined, false, CursorGrabMode::Confined)]
+    #[test_case(CursorGrabMode::Locked, false, CursorGrabMode::Confined)]
+    fn should_fall_back_to_supported_modes(
+        mode: CursorGrabMode,
+        supports_locking: bool,
+        expected: CursorGrabMode,
+    ) {
+        assert_eq!(mode.supported(supports_locking), expected);
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{CursorGrabMode, Window, WindowDimensions, WindowSettings};
+
+/// Provides a [`Window`] which doesn't open a native window.
+///
+/// The headless window stores the same state as a real window would, but all changes stop there.
+/// This makes it useful for dedicated servers, and tests, where the window API is needed, but a
+/// window is not.
+///
+/// A headless window has no native window handle.
+#[derive(Debug)]
+pub struct HeadlessWindow {
+    title: String,
+    size: WindowDimensions,
+    cursor_grab: CursorGrabMode,
+    cursor_visible: bool,
+}
+
+impl HeadlessWindow {
+    /// Creates a new headless window from the provided [`WindowSettings`].
+    pub fn new(settings: WindowSettings) -> Self {
+        Self {
+            title: settings.title,
+            size: settings.size,
+            cursor_grab: CursorGrabMode::None,
+            cursor_visible: true,
+        }
+    }
+}
+
+impl Window for HeadlessWindow {
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn set_title(&mut self, title: &str) {
+        self.title = title.to_string();
+    }
+
+    fn size(&self) -> WindowDimensions {
+        self.size
+    }
+
+    fn cursor_grab(&self) -> CursorGrabMode {
+        self.cursor_grab
+    }
+
+    fn set_cursor_grab(&mut self, grab: CursorGrabMode) {
+        self.cursor_grab = grab;
+    }
+
+    fn is_cursor_visible(&self) -> bool {
+        self.cursor_visible
+    }
+
+    fn set_cursor_visible(&mut self, visible: bool) {
+        self.cursor_visible = visible;
+    }
+}
+
+#[cfg(test)]
+mod headless_window_tests {
+    use super::*;
+
+    #[test]
+    fn should_report_configured_settings() {
+        let window = HeadlessWindow::new(
+            WindowSettings::new()
+                .with_title("Server")
+                .with_size(WindowDimensions::new(640, 480)),
+        );
+
+        assert_eq!(window.title(), "Server");
+        assert_eq!(window.size(), WindowDimensions::new(640, 480));
+    }
+
+    #[test]
+    fn should_store_changes() {
+        let mut window = HeadlessWindow::new(WindowSettings::new());
+
+        window.set_title("Changed");
+        window.set_cursor_grab(CursorGrabMode::Locked);
+        window.set_cursor_visible(false);
+
+        assert_eq!(window.title(), "Changed");
+        assert_eq!(window.cursor_grab(), CursorGrabMode::Locked);
+        assert!(!window.is_cursor_visible());
+    }
+}
@@ -4,10 +4,20 @@
 //! See [wolf_engine::window](https://docs.rs/wolf_engine/latest/wolf_engine/window/index.html/)
 //! for more details.
 
+mod cursor_grab_mode;
+pub use cursor_grab_mode::*;
+mod headless_window;
+pub use headless_window::*;
 mod window;
 pub use window::*;
+mod window_event;
+pub use window_event::*;
+mod window_id;
+pub use window_id::*;
 mod window_dimensions;
 pub use window_dimensions::*;
+mod window_mode;
+pub use window_mode::*;
 mod window_settings;
 pub use window_settings::*;
 
@@ -1,2 +1,124 @@
+use crate::{CursorGrabMode, WindowDimensions};
+
 /// Provides a high-level, back-end agnostic window API.
-pub trait Window {}
+///
+/// Implementations are expected to store the window's state themselves, and forward any changes
+/// to the native window, if there is one.  Getters should always reflect the last value set, even
+/// when there is no native window to forward to.
+pub trait Window {
+    /// Returns the window's current title.
+    fn title(&self) -> &str;
+
+    /// Changes the window's title.
+    fn set_title(&mut self, title: &str);
+
+    /// Returns the size of the window's drawable area, in pixels.
+    fn size(&self) -> WindowDimensions;
+
+    /// Returns the requested [`CursorGrabMode`].
+    fn cursor_grab(&self) -> CursorGrabMode;
+
+    /// Changes the [`CursorGrabMode`].
+    ///
+    /// The requested mode is stored, so it can be re-applied when the window regains focus.
+    /// Back-ends which can't lock the cursor should apply [`CursorGrabMode::Confined`] instead of
+    /// [`CursorGrabMode::Locked`] (see [`CursorGrabMode::supported()`]), but still report the
+    /// requested mode.
+    fn set_cursor_grab(&mut self, grab: CursorGrabMode);
+
+    /// Returns `true` if the cursor is visible while over the window.
+    fn is_cursor_visible(&self) -> bool;
+
+    /// Shows, or hides the cursor while it is over the window.
+    fn set_cursor_visible(&mut self, visible: bool);
+}
+
+#[cfg(test)]
+mod window_tests {
+    use super::*;
+    use crate::WindowSettings;
+
+    struct TestWindow {
+        title: String,
+        size: WindowDimensions,
+        cursor_grab: CursorGrabMode,
+        applied_cursor_grab: CursorGrabMode,
+        cursor_visible: bool,
+    }
+
+    impl TestWindow {
+        fn new(settings: WindowSettings) -> Self {
+            Self {
+                title: settings.title,
+                size: settings.size,
+                cursor_grab: CursorGrabMode::None,
+                applied_cursor_grab: CursorGrabMode::None,
+                cursor_visible: true,
+            }
+        }
+    }
+
+    impl Window for TestWindow {
+        fn title(&self) -> &str {
+            &self.title
+        }
+
+        fn set_title(&mut self, title: &str) {
+            self.title = title.to_string();
+        }
+
+        fn size(&self) -> WindowDimensions {
+            self.size
+        }
+
+        fn cursor_grab(&self) -> CursorGrabMode {
+            self.cursor_grab
+        }
+
+        fn set_cursor_grab(&mut self, grab: CursorGrabMode) {
+            self.cursor_grab = grab;
+            self.applied_cursor_grab = grab.supported(false);
+        }
+
+        fn is_cursor_visible(&self) -> bool {
+            self.cursor_visible
+        }
+
+        fn set_cursor_visible(&mut self, visible: bool) {
+            self.cursor_visible = visible;
+        }
+    }
+
+    #[test]
+    fn should_change_title() {
+        let mut window = TestWindow::new(WindowSettings::new().with_title("Level 1"));
+        assert_eq!(window.title(), "Level 1");
+
+        window.set_title("Level 2");
+
+        assert_eq!(window.title(), "Level 2");
+    }
+
+    #[test]
+    fn should_store_cursor_state() {
+        let mut window = TestWindow::new(WindowSettings::new());
+        assert_eq!(window.cursor_grab(), CursorGrabMode::None);
+        assert!(window.is_cursor_visible());
+
+        window.set_cursor_grab(CursorGrabMode::Confined);
+        window.set_cursor_visible(false);
+
+        assert_eq!(window.cursor_grab(), CursorGrabMode::Confined);
+        assert!(!window.is_cursor_visible());
+    }
+
+    #[test]
+    fn should_keep_requested_cursor_grab_when_locking_is_unsupported() {
+        let mut window = TestWindow::new(WindowSettings::new());
+
+        window.set_cursor_grab(CursorGrabMode::Locked);
+
+        assert_eq!(window.cursor_grab(), CursorGrabMode::Locked);
+        assert_eq!(window.applied_cursor_grab, CursorGrabMode::Confined);
+    }
+}
@@ -17,6 +17,28 @@ impl WindowDimensions {
     pub fn new(width: u32, height: u32) -> Self {
         Self { width, height }
     }
+
+    /// Returns the ratio of the width to the height.
+    ///
+    /// Returns `0.0` if the height is `0`.
+    pub fn aspect_ratio(&self) -> f32 {
+        if self.height == 0 {
+            0.0
+        } else {
+            self.width as f32 / self.height as f32
+        }
+    }
+
+    /// Returns a new set of dimensions, scaled by the provided factor, and rounded to the nearest
+    /// pixel.
+    ///
+    /// This is useful for converting between logical, and physical pixels on high-DPI displays.
+    pub fn scaled(&self, factor: f64) -> WindowDimensions {
+        Self::new(
+            (self.width as f64 * factor).round() as u32,
+            (self.height as f64 * factor).round() as u32,
+        )
+    }
 }
 
 impl From<(u32, u32)> for WindowDimensions {
@@ -25,6 +47,12 @@ impl From<(u32, u32)> for WindowDimensions {
     }
 }
 
+impl From<WindowDimensions> for (u32, u32) {
+    fn from(dimensions: WindowDimensions) -> Self {
+        (dimensions.width, dimensions.height)
+    }
+}
+
 #[cfg(test)]
 mod window_dimensions_tests {
     use super::*;
@@ -43,6 +71,32 @@ mod window_dimensions_tests {
         assert_eq!(dimensions.height, 600);
     }
 
+    #[test]
+    fn should_convert_into_tuple() {
+        let (width, height): (u32, u32) = WindowDimensions::new(800, 600).into();
+        assert_eq!(width, 800);
+        assert_eq!(height, 600);
+    }
+
+    #[test]
+    fn should_calculate_aspect_ratio() {
+        let dimensions = WindowDimensions::new(1920, 1080);
+        assert!((dimensions.aspect_ratio() - 16.0 / 9.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn should_return_zero_aspect_ratio_for_zero_height() {
+        let dimensions = WindowDimensions::new(800, 0);
+        assert_eq!(dimensions.aspect_ratio(), 0.0);
+    }
+
+    #[test]
+    fn should_scale_dimensions() {
+        let dimensions = WindowDimensions::new(800, 600);
+        assert_eq!(dimensions.scaled(1.5), WindowDimensions::new(1200, 900));
+        assert_eq!(dimensions.scaled(0.5), WindowDimensions::new(400, 300));
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn should_implement_deserialize() {
@@ -0,0 +1,49 @@
+use wolf_engine_core::events::Event;
+
+use crate::WindowDimensions;
+
+/// Provides the events emitted by a window.
+///
+/// Window back-ends send these events through the engine's
+/// [`MainEventSender`](wolf_engine_core::events::MainEventSender), so they arrive on the
+/// [`EventLoop`](wolf_engine_core::events::EventLoop) alongside every other event.
+#[non_exhaustive]
+#[derive(Event, Debug, PartialEq, Clone, Copy)]
+pub enum WindowEvent {
+    /// Emitted when the window has been resized.
+    Resized(WindowDimensions),
+
+    /// Emitted when the user has asked to close the window.
+    CloseRequested,
+
+    /// Emitted when the window gains (`true`), or loses (`false`) focus.
+    Focused(bool),
+
+    /// Emitted when the window's DPI scale factor has changed.
+    ScaleFactorChanged(f64),
+}
+
+#[cfg(test)]
+mod window_event_tests {
+    use wolf_engine_core::events::{EventReceiver, EventSender};
+
+    use super::*;
+
+    #[test]
+    fn should_send_resize_events_through_the_event_loop() {
+        let (mut event_loop, context) = wolf_engine_core::init().build().unwrap();
+
+        context
+            .event_sender()
+            .send_event(Box::from(WindowEvent::Resized(WindowDimensions::new(
+                1280, 720,
+            ))))
+            .unwrap();
+        let event = event_loop.next_event().unwrap();
+
+        assert_eq!(
+            *event.downcast::<WindowEvent>().unwrap(),
+            WindowEvent::Resized(WindowDimensions::new(1280, 720))
+        );
+    }
+}
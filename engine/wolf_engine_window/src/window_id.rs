@@ -0,0 +1,56 @@
+use std::fmt::{Debug, Display, Formatter, Result};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static NEXT_WINDOW_ID: AtomicU64 = AtomicU64::new(0);
+
+/// A unique identifier for a window.
+///
+/// Ids are handed out by [`WindowId::next()`], and are never reused during the lifetime of the
+/// process.  Both [`Display`], and [`Debug`] print ids as `Window(n)`.
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Ord, PartialOrd)]
+pub struct WindowId(u64);
+
+impl WindowId {
+    /// Returns a new, unique id.
+    pub fn next() -> Self {
+        Self(NEXT_WINDOW_ID.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Returns the numeric value of the id.
+    pub fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Display for WindowId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "Window({})", self.0)
+    }
+}
+
+impl Debug for WindowId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod window_id_tests {
+    use super::*;
+
+    #[test]
+    fn should_generate_unique_ids() {
+        let first = WindowId::next();
+        let second = WindowId::next();
+
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn should_display_the_numeric_id() {
+        let id = WindowId::next();
+
+        assert_eq!(id.to_string(), format!("Window({})", id.as_u64()));
+        assert_eq!(format!("{:?}", id), id.to_string());
+    }
+}
@@ -0,0 +1,33 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Represents the ways a window can be displayed.
+#[derive(Default, Debug, Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WindowMode {
+    /// A regular, decorated window. (Default)
+    #[default]
+    Windowed,
+
+    /// A borderless window covering the whole screen, at the screen's current resolution.
+    BorderlessFullscreen,
+
+    /// An exclusive fullscreen window, which changes the screen's video mode.
+    ExclusiveFullscreen {
+        /// The horizontal resolution, in pixels.
+        width: u32,
+
+        /// The vertical resolution, in pixels.
+        height: u32,
+
+        /// The refresh rate, in hertz.
+        refresh: u32,
+    },
+}
+
+impl WindowMode {
+    /// Returns `true` if the mode covers the whole screen.
+    pub fn is_fullscreen(&self) -> bool {
+        !matches!(self, Self::Windowed)
+    }
+}
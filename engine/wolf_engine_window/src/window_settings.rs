@@ -1,10 +1,37 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::{WindowDimensions, WindowMode};
+
 /// Provides the settings used to create a window.
-#[derive(Default, Debug, PartialEq, Eq)]
+///
+/// Back-ends creating a window from these settings are responsible for translating them into
+/// whatever their native equivalent is.
+///
+/// Plain values are declared before tables, so the settings can be serialized to formats such as
+/// TOML, which don't allow values after tables.
+#[derive(Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct WindowSettings {}
+pub struct WindowSettings {
+    /// The window's title.  Defaults to "Wolf Engine".
+    pub title: String,
+
+    /// The way the window is displayed.  Defaults to [`WindowMode::Windowed`].
+    pub mode: WindowMode,
+
+    /// The size of the window's drawable area, in pixels.  Defaults to 1280x720.
+    pub size: WindowDimensions,
+}
+
+impl Default for WindowSettings {
+    fn default() -> Self {
+        Self {
+            title: "Wolf Engine".to_string(),
+            mode: WindowMode::default(),
+            size: WindowDimensions::new(1280, 720),
+        }
+    }
+}
 
 impl WindowSettings {
     /// Create a new instance of `WindowSettings` with the default settings.
@@ -15,4 +42,140 @@ impl WindowSettings {
     }
 }
 
-impl WindowSettings {}
+impl WindowSettings {
+    /// Sets the window's title.
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    /// Sets the size of the window's drawable area, in pixels.
+    pub fn with_size(mut self, size: WindowDimensions) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the [`WindowMode`] the window is displayed in.
+    pub fn with_mode(mut self, mode: WindowMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+#[cfg(test)]
+mod window_settings_tests {
+    use super::*;
+
+    #[test]
+    fn should_set_title() {
+        let settings = WindowSettings::new().with_title("My Game");
+
+        assert_eq!(settings.title, "My Game");
+    }
+
+    #[test]
+    fn should_set_size() {
+        let settings = WindowSettings::new().with_size(WindowDimensions::new(800, 600));
+
+        assert_eq!(settings.size, WindowDimensions::new(800, 600));
+    }
+
+    #[test]
+    fn should_default_to_windowed_mode() {
+        let settings = WindowSettings::new();
+
+        assert_eq!(settings.mode, WindowMode::Windowed);
+        assert!(!settings.mode.is_fullscreen());
+    }
+
+    #[test]
+    fn should_set_window_mode() {
+        let settings = WindowSettings::new().with_mode(WindowMode::BorderlessFullscreen);
+
+        assert_eq!(settings.mode, WindowMode::BorderlessFullscreen);
+        assert!(settings.mode.is_fullscreen());
+    }
+
+    #[test]
+    fn should_keep_exclusive_fullscreen_resolution() {
+        let settings = WindowSettings::new().with_mode(WindowMode::ExclusiveFullscreen {
+            width: 1920,
+            height: 1080,
+            refresh: 144,
+        });
+
+        match settings.mode {
+            WindowMode::ExclusiveFullscreen {
+                width,
+                height,
+                refresh,
+            } => {
+                assert_eq!(width, 1920);
+                assert_eq!(height, 1080);
+                assert_eq!(refresh, 144);
+            }
+            _ => panic!("The window mode should be exclusive fullscreen"),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_round_trip_through_serde() {
+        let settings = WindowSettings::new()
+            .with_title("My Game")
+            .with_size(WindowDimensions::new(800, 600))
+            .with_mode(WindowMode::BorderlessFullscreen);
+
+        let toml_str = toml::to_string(&settings).unwrap();
+        let deserialized: WindowSettings = toml::from_str(&toml_str).unwrap();
+
+        assert_eq!(deserialized, settings);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_deserialize_from_a_config_file() {
+        let toml_str = r#"
+        title = "My Game"
+        mode = "BorderlessFullscreen"
+
+        [size]
+        width = 800
+        height = 600
+        "#;
+
+        let settings: WindowSettings = toml::from_str(toml_str).unwrap();
+
+        assert_eq!(
+            settings,
+            WindowSettings::new()
+                .with_title("My Game")
+                .with_size(WindowDimensions::new(800, 600))
+                .with_mode(WindowMode::BorderlessFullscreen)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_deserialize_exclusive_fullscreen_mode() {
+        let toml_str = r#"
+        title = "My Game"
+        mode = { ExclusiveFullscreen = { width = 1920, height = 1080, refresh = 144 } }
+
+        [size]
+        width = 1920
+        height = 1080
+        "#;
+
+        let settings: WindowSettings = toml::from_str(toml_str).unwrap();
+
+        assert_eq!(
+            settings.mode,
+            WindowMode::ExclusiveFullscreen {
+                width: 1920,
+                height: 1080,
+                refresh: 144,
+            }
+        );
+    }
+}